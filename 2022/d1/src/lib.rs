@@ -0,0 +1,59 @@
+use aoc_common::{parse_token, ParseError, Rng};
+use min_max_heap::MinMaxHeap;
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// total food carried by every elf
+pub fn parse(input: &str) -> Result<MinMaxHeap<i32>, ParseError> {
+    load_elf_food(input)
+}
+
+pub fn solve_part1(heap: &MinMaxHeap<i32>) -> i32 {
+    heap.peek_max().copied().unwrap_or(0)
+}
+
+pub fn solve_part2(heap: &MinMaxHeap<i32>) -> i32 {
+    let mut heap = heap.clone();
+
+    let mut ans = 0;
+    for _ in 0..3 {
+        ans += heap.pop_max().unwrap_or(0);
+    }
+    ans
+}
+
+fn load_elf_food(input: &str) -> Result<MinMaxHeap<i32>, ParseError> {
+    let mut heap = MinMaxHeap::<i32>::new();
+
+    let mut cur_elf_food = 0;
+    for line in input.lines() {
+        if line.is_empty() {
+            heap.push(cur_elf_food);
+            cur_elf_food = 0;
+        } else {
+            cur_elf_food += parse_token::<i32>(input, line, "a calorie count")?;
+        }
+    }
+    // last elf is not followed by an empty line
+    heap.push(cur_elf_food);
+
+    Ok(heap)
+}
+
+// size elves carrying one to six snacks each
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut elves = Vec::new();
+    for _ in 0..size.max(1) {
+        let snacks = (0..rng.range(1, 6))
+            .map(|_| rng.range(1000, 60000).to_string())
+            .collect::<Vec<_>>();
+        elves.push(snacks.join("\n"));
+    }
+    elves.join("\n\n") + "\n"
+}
@@ -0,0 +1,234 @@
+use std::collections::VecDeque;
+
+use aoc_common::{next_token, parse_token, Frame, FrameSink, ParseError, Rng};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Effect {
+    Noop,
+    AddX(i32),
+}
+
+struct PixelGraph {
+    x: i32,
+    graph: Vec<char>,
+}
+
+impl PixelGraph {
+    fn new() -> PixelGraph {
+        PixelGraph {
+            graph: vec![],
+            x: 0,
+        }
+    }
+
+    fn add_pixel(&mut self, sprite_position: (i32, i32)) {
+        let (from, to) = sprite_position;
+
+        if self.x < from || self.x > to {
+            self.graph.push('.');
+        } else {
+            self.graph.push('#');
+        }
+        self.x += 1;
+        if self.x == 40 {
+            self.x = 0;
+        }
+    }
+
+    // one line of text per row of the crt
+    fn render(&self) -> String {
+        self.graph
+            .chunks(40)
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    // pixels drawn so far, following the beam
+    fn get_frame(&self) -> Frame {
+        let mut frame = Frame::new();
+        for (index, &pixel) in self.graph.iter().enumerate() {
+            frame.set(((index % 40) as i32, (index / 40) as i32), pixel);
+        }
+        if let Some(last) = self.graph.len().checked_sub(1) {
+            frame.focus_on(((last % 40) as i32, (last / 40) as i32));
+        }
+        frame
+    }
+}
+
+struct Game {
+    cycle: i32,
+    signal_strength: i32,
+    queue: VecDeque<Effect>,
+}
+
+impl Game {
+    fn new() -> Game {
+        Game {
+            cycle: 1,
+            signal_strength: 1,
+            queue: VecDeque::new(),
+        }
+    }
+
+    // draws the crt, calling step after every pixel
+    fn simulate_v2(&mut self, program: &[Effect], mut step: impl FnMut(&PixelGraph)) -> PixelGraph {
+        let mut sprite_from = 0;
+        let mut sprite_to = 2;
+        let mut pixel_graph = PixelGraph::new();
+
+        for effect in program {
+            let (expected_signal_strength, expected_cycle) = self.queue_effect(*effect);
+            for _ in 0..expected_cycle {
+                pixel_graph.add_pixel((sprite_from, sprite_to));
+                step(&pixel_graph);
+            }
+
+            sprite_from += expected_signal_strength;
+            sprite_to += expected_signal_strength;
+        }
+
+        pixel_graph
+    }
+
+    fn simulate(&mut self) -> i32 {
+        // 20, 60, 100, 140, 180, 220
+        let mut ptr = 0;
+        let interesting_cycle_list = [20, 60, 100, 140, 180, 220];
+        let mut solution_1_ans = 0;
+        let mut track_list: Vec<i32> = vec![];
+        while !self.queue.is_empty() {
+            let effect = self.queue.pop_front().unwrap();
+
+            let (expected_signal_strength, expected_cycle);
+
+            match effect {
+                Effect::Noop => {
+                    (expected_signal_strength, expected_cycle) = self.get_noop_expected()
+                }
+                Effect::AddX(value_to_add) => {
+                    (expected_signal_strength, expected_cycle) =
+                        self.get_addx_expected(value_to_add)
+                }
+            }
+
+            // check that ptr not out of bound and
+            if ptr < interesting_cycle_list.len() && interesting_cycle_list[ptr] < expected_cycle {
+                solution_1_ans += self.signal_strength * interesting_cycle_list[ptr];
+                ptr += 1;
+                track_list.push(self.signal_strength);
+            }
+
+            self.cycle = expected_cycle;
+            self.signal_strength = expected_signal_strength;
+        }
+
+        log::debug!("track list: {:?}", track_list);
+        solution_1_ans
+    }
+
+    // get expected signal strength and cycle
+    fn queue_effect(&mut self, effect: Effect) -> (i32, i32) {
+        // get effect timeline
+        // apply effect to game state
+        self.queue.push_back(effect);
+
+        match effect {
+            Effect::Noop => (0, 1),
+            Effect::AddX(value_to_add) => (value_to_add, 2),
+        }
+    }
+
+    // return expected signal strenght and cycle
+    fn get_noop_expected(&mut self) -> (i32, i32) {
+        (self.signal_strength, self.cycle + 1)
+    }
+
+    fn get_addx_expected(&mut self, value_to_add: i32) -> (i32, i32) {
+        (value_to_add + self.signal_strength, self.cycle + 2)
+    }
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?))
+}
+
+// one instruction per line
+pub fn parse(input: &str) -> Result<Vec<Effect>, ParseError> {
+    let mut program = Vec::new();
+    for line in input.lines() {
+        let mut split = line.split_whitespace();
+        let command = next_token(input, line, &mut split, "noop or addx")?;
+        match command {
+            "noop" => program.push(Effect::Noop),
+            "addx" => {
+                let token = next_token(input, line, &mut split, "a number")?;
+                program.push(Effect::AddX(parse_token(input, token, "a number")?));
+            }
+            _ => return Err(ParseError::at(input, command, "noop or addx")),
+        }
+    }
+    Ok(program)
+}
+
+// sum of the interesting signal strengths
+pub fn solve_part1(program: &[Effect]) -> i32 {
+    let mut game = Game::new();
+    for effect in program {
+        game.queue_effect(*effect);
+    }
+    game.simulate()
+}
+
+// rendered crt image
+pub fn solve_part2(program: &[Effect]) -> String {
+    let mut game = Game::new();
+    game.simulate_v2(program, |_| {}).render()
+}
+
+// one frame per cycle as the crt draws its image, the last one being the
+// answer of part two
+pub fn replay(program: &[Effect], _part: u8, sink: &mut dyn FrameSink) {
+    let mut game = Game::new();
+    let mut cycle = 0;
+    let pixel_graph = game.simulate_v2(program, |pixel_graph| {
+        cycle += 1;
+        if sink.wants_frame() {
+            let mut frame = pixel_graph.get_frame();
+            frame.set_caption(format!("cycle {}", cycle));
+            sink.frame(&frame);
+        }
+    });
+    if sink.final_only() {
+        let mut frame = pixel_graph.get_frame();
+        frame.set_caption(format!("cycle {}", cycle));
+        sink.frame(&frame);
+    }
+}
+
+// at least size instructions, and enough of them to draw all 240 pixels.
+// the register stays within the width of the screen
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut output = String::new();
+    let mut cycles = 0;
+    let mut count = 0;
+    let mut x: i64 = 1;
+    while cycles < 240 || count < size {
+        if rng.one_in(3) {
+            output += "noop\n";
+            cycles += 1;
+        } else {
+            let value = rng.range((-x).max(-10), (39 - x).min(10));
+            output += &format!("addx {}\n", value);
+            x += value;
+            cycles += 2;
+        }
+        count += 1;
+    }
+    output
+}
@@ -0,0 +1,450 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+};
+
+use aoc_common::{next_token, parse_token, ParseError, Rng};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Copy)]
+enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Square,
+}
+
+#[derive(Clone, Debug)]
+struct OperationImpl {
+    operation: Operation,
+    x: String,
+}
+
+impl OperationImpl {
+    fn new(operation: String, x: String) -> OperationImpl {
+        if x == "old" {
+            return OperationImpl {
+                operation: Operation::Square,
+                x,
+            };
+        }
+        // convert String to &str
+        let operation_adr = &operation[..];
+        match operation_adr {
+            "+" => OperationImpl {
+                operation: Operation::Add,
+                x,
+            },
+            "-" => OperationImpl {
+                operation: Operation::Sub,
+                x,
+            },
+            "*" => OperationImpl {
+                operation: Operation::Mul,
+                x,
+            },
+            "/" => OperationImpl {
+                operation: Operation::Div,
+                x,
+            },
+            _ => {
+                panic!()
+            }
+        }
+    }
+
+    fn execute(&self, a: i128) -> i128 {
+        let b = self.x.clone();
+        match self.operation {
+            Operation::Add => a + b.parse::<i128>().unwrap(),
+            Operation::Sub => a - b.parse::<i128>().unwrap(),
+            Operation::Mul => a * b.parse::<i128>().unwrap(),
+            Operation::Div => a / b.parse::<i128>().unwrap(),
+            Operation::Square => a * a,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Copy)]
+struct Test {
+    divisible: i128,
+    monkey_1: i128,
+    monkey_2: i128,
+}
+
+impl Test {
+    fn new(divisible: i128, monkey_1: i128, monkey_2: i128) -> Test {
+        Test {
+            divisible,
+            monkey_1,
+            monkey_2,
+        }
+    }
+
+    fn get_monkey_identifier(&self, item: i128) -> i128 {
+        if item % self.divisible == 0 {
+            self.monkey_1
+        } else {
+            self.monkey_2
+        }
+    }
+
+    fn get_divisible(&self) -> i128 {
+        self.divisible
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    item_list: VecDeque<i128>,
+    operation: OperationImpl,
+    test: Test,
+    inspect_count: i128,
+}
+
+impl Monkey {
+    fn new(item_list: VecDeque<i128>, operation: OperationImpl, test: Test) -> Monkey {
+        Monkey {
+            item_list,
+            operation,
+            test,
+            inspect_count: 0,
+        }
+    }
+
+    fn add_item(&mut self, item: i128) {
+        self.item_list.push_back(item);
+    }
+
+    fn pop(&mut self) -> i128 {
+        self.item_list.pop_front().unwrap()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Game {
+    monkey_map: HashMap<i128, RefCell<Monkey>>,
+}
+
+impl Game {
+    fn new() -> Game {
+        Game {
+            monkey_map: HashMap::new(),
+        }
+    }
+
+    fn get_divisible_lcm(&self) -> i128 {
+        self.monkey_map
+            .values()
+            .map(|x| x.borrow().test.get_divisible())
+            .product()
+    }
+
+    fn execute(&mut self, n: i128) {
+        // get current monkey item from item list
+
+        for i in 0..self.monkey_map.keys().len() {
+            let identifier = i as i128;
+
+            while !self
+                .monkey_map
+                .get(&identifier)
+                .unwrap()
+                .borrow()
+                .item_list
+                .is_empty()
+            {
+                let item_to_move = self.monkey_map.get(&identifier).unwrap().borrow_mut().pop();
+                self.monkey_map
+                    .get(&identifier)
+                    .unwrap()
+                    .borrow_mut()
+                    .inspect_count += 1;
+
+                let mut new_item_value = self
+                    .monkey_map
+                    .get(&identifier)
+                    .unwrap()
+                    .borrow()
+                    .operation
+                    .execute(item_to_move)
+                    / n;
+                // keep the worry level bounded when it is no longer divided down
+                if n == 1 {
+                    new_item_value %= self.get_divisible_lcm();
+                }
+
+                let monkey_to_pass = self
+                    .monkey_map
+                    .get(&identifier)
+                    .unwrap()
+                    .borrow()
+                    .test
+                    .get_monkey_identifier(new_item_value);
+
+                self.monkey_map
+                    .get(&monkey_to_pass)
+                    .unwrap()
+                    .borrow_mut()
+                    .add_item(new_item_value);
+            }
+        }
+    }
+
+    fn add_monkey(&mut self, identifier: i128, monkey: Monkey) {
+        self.monkey_map.insert(identifier, RefCell::new(monkey));
+    }
+
+    fn get_solution_1(&self) -> i128 {
+        // sort monkey map by inspect count
+        let monkey_map = self.monkey_map.clone();
+        let mut monkey_list = monkey_map
+            .values()
+            .map(|x| x.borrow().clone())
+            .collect::<Vec<Monkey>>();
+        monkey_list.sort_by_key(|a| a.inspect_count);
+
+        // get last 2 monkey
+        let monkey_1 = monkey_list.pop().unwrap();
+        let monkey_2 = monkey_list.pop().unwrap();
+        monkey_1.inspect_count * monkey_2.inspect_count
+    }
+
+    fn print(&self) {
+        for (identifier, monkey) in &self.monkey_map {
+            log::trace!("{}: {:?}", identifier, monkey.borrow().item_list);
+        }
+    }
+    fn print_inpected_count(&self) {
+        for i in 0..self.monkey_map.keys().len() {
+            let identifier = i as i128;
+            log::trace!(
+                "monkey: {}, inspect_count: {}",
+                identifier,
+                self.monkey_map
+                    .get(&identifier)
+                    .unwrap()
+                    .borrow()
+                    .inspect_count
+            );
+        }
+    }
+}
+
+// next line of a monkey block, with its leading whitespace and prefix removed
+fn next_field<'a>(
+    input: &str,
+    monkey_block: &'a str,
+    monkey_lines: &mut std::str::Lines<'a>,
+    prefix: &str,
+) -> Result<&'a str, ParseError> {
+    let expected = format!("'{}'", prefix.trim());
+    let line = match monkey_lines.next() {
+        Some(line) => line.trim_start(),
+        None => {
+            return Err(ParseError::at(
+                input,
+                &monkey_block[monkey_block.len()..],
+                expected,
+            ))
+        }
+    };
+    line.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, line, expected))
+}
+
+// monkey_count is used to check that every throw goes to an existing monkey
+pub fn parse_monkey_block(
+    input: &str,
+    monkey_block: &str,
+    monkey_count: usize,
+) -> Result<(i128, Monkey), ParseError> {
+    let mut monkey_lines = monkey_block.lines();
+    let line_1 = next_field(input, monkey_block, &mut monkey_lines, "Monkey ")?;
+    let identifier = match line_1.strip_suffix(':') {
+        Some(identifier) => identifier,
+        None => return Err(ParseError::at(input, &line_1[line_1.len()..], "':'")),
+    };
+    let monkey_identifier = parse_token::<i128>(input, identifier, "a monkey number")?;
+
+    let line_2 = next_field(input, monkey_block, &mut monkey_lines, "Starting items:")?;
+    let mut monkey_item_list = VecDeque::new();
+    if !line_2.trim().is_empty() {
+        for item in line_2.split(',') {
+            monkey_item_list.push_back(parse_token::<i128>(input, item.trim(), "a worry level")?);
+        }
+    }
+
+    let line_3 = next_field(
+        input,
+        monkey_block,
+        &mut monkey_lines,
+        "Operation: new = old ",
+    )?;
+    let mut operation_content = line_3.split_whitespace();
+    let op = next_token(input, line_3, &mut operation_content, "+, -, * or /")?;
+    if !["+", "-", "*", "/"].contains(&op) {
+        return Err(ParseError::at(input, op, "+, -, * or /"));
+    }
+    let x = next_token(input, line_3, &mut operation_content, "old or a number")?;
+    if x != "old" {
+        parse_token::<i128>(input, x, "old or a number")?;
+    }
+    let operation = OperationImpl::new(op.to_string(), x.to_string());
+
+    let line_4 = next_field(
+        input,
+        monkey_block,
+        &mut monkey_lines,
+        "Test: divisible by ",
+    )?;
+    let divisible = parse_token::<i128>(input, line_4.trim(), "a positive number")?;
+    if divisible <= 0 {
+        return Err(ParseError::at(input, line_4.trim(), "a positive number"));
+    }
+
+    let mut targets = [0; 2];
+    for (target, prefix) in targets
+        .iter_mut()
+        .zip(["If true: throw to monkey ", "If false: throw to monkey "])
+    {
+        let line = next_field(input, monkey_block, &mut monkey_lines, prefix)?;
+        let expected = format!("a monkey number below {}", monkey_count);
+        *target = parse_token::<i128>(input, line.trim(), &expected)?;
+        if !(0..monkey_count as i128).contains(target) {
+            return Err(ParseError::at(input, line.trim(), expected));
+        }
+    }
+    let test = Test::new(divisible, targets[0], targets[1]);
+
+    let monkey = Monkey::new(monkey_item_list, operation, test);
+
+    Ok((monkey_identifier, monkey))
+}
+
+pub fn load_game(input: &str) -> Result<Game, ParseError> {
+    let mut game = Game::new();
+    let monkey_block_list = input
+        .split("\n\n")
+        .filter(|monkey_block| !monkey_block.trim().is_empty())
+        .collect::<Vec<&str>>();
+    // monkeys are played in order of their number, so they have to be
+    // numbered from 0 and at least two are needed for the monkey business
+    if monkey_block_list.len() < 2 {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "another monkey",
+        ));
+    }
+    for (index, monkey_block) in monkey_block_list.iter().enumerate() {
+        let (identifier, monkey) =
+            parse_monkey_block(input, monkey_block, monkey_block_list.len())?;
+        if identifier != index as i128 {
+            return Err(ParseError::at(
+                input,
+                monkey_block,
+                format!("Monkey {}", index),
+            ));
+        }
+        game.add_monkey(identifier, monkey);
+    }
+    Ok(game)
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// monkeys keyed by their identifier
+pub fn parse(input: &str) -> Result<Game, ParseError> {
+    load_game(input)
+}
+
+// rounds of monkey business in each part
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part1_rounds: usize,
+    pub part2_rounds: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_rounds: 20,
+            part2_rounds: 10000,
+        }
+    }
+}
+
+pub fn solve_part1(game: &Game) -> i128 {
+    solve_part1_with(game, &Params::default())
+}
+
+pub fn solve_part2(game: &Game) -> i128 {
+    solve_part2_with(game, &Params::default())
+}
+
+// monkey business with worry divided by 3
+pub fn solve_part1_with(game: &Game, params: &Params) -> i128 {
+    let mut game = game.clone();
+    for _ in 0..params.part1_rounds {
+        game.execute(3);
+    }
+    game.get_solution_1()
+}
+
+// monkey business with worry no longer divided
+pub fn solve_part2_with(game: &Game, params: &Params) -> i128 {
+    let mut game = game.clone();
+    for i in 0..params.part2_rounds {
+        game.execute(1);
+        // dumping every monkey is slow, skip it unless it is shown
+        if log::log_enabled!(log::Level::Trace) {
+            log::trace!("round {}:", i);
+            game.print_inpected_count();
+            game.print();
+        }
+    }
+    game.get_solution_1()
+}
+
+// size monkeys, two to nine of them, each testing with its own prime. old
+// * old is left out as part one never takes the worry levels modulo the
+// tests and squaring them would overflow
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 9);
+    let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+    let mut blocks = Vec::new();
+    for (monkey, prime) in primes.iter().take(count).enumerate() {
+        let items = (0..rng.range(1, 5))
+            .map(|_| rng.range(50, 99).to_string())
+            .collect::<Vec<_>>();
+        let operation = match rng.one_in(2) {
+            true => format!("* {}", rng.range(2, 7)),
+            false => format!("+ {}", rng.range(1, 9)),
+        };
+        let mut others = (0..count)
+            .filter(|&other| other != monkey)
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut others);
+        let if_false = others.get(1).unwrap_or(&others[0]);
+        blocks.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            monkey,
+            items.join(", "),
+            operation,
+            prime,
+            others[0],
+            if_false
+        ));
+    }
+    blocks.join("\n")
+}
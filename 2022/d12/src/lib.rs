@@ -0,0 +1,195 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{Grid, ParseError, Rng};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Position {
+    x: i32,
+    y: i32,
+    steps: i32,
+}
+
+impl Position {
+    fn new(x: i32, y: i32, steps: i32) -> Position {
+        Position { x, y, steps }
+    }
+
+    fn get_neighbours(&self, map: &Grid<char>) -> Vec<(i32, i32)> {
+        let current_elevation = update_elavation(map[(self.x, self.y)]);
+
+        let neighbours = map
+            .neighbours_4((self.x, self.y))
+            .filter(|&neighbour| {
+                update_elavation(map[neighbour]) as u8 <= current_elevation as u8 + 1
+            })
+            .collect::<Vec<(i32, i32)>>();
+        log::trace!("neighbours: {:?}", neighbours);
+        neighbours
+    }
+
+    fn get_path_from(&self, map: &Grid<char>) -> Vec<(i32, i32)> {
+        let current_elevation = update_elavation(map[(self.x, self.y)]);
+
+        let path = map
+            .neighbours_4((self.x, self.y))
+            .filter(|&neighbour| {
+                update_elavation(map[neighbour]) as u8 >= current_elevation as u8 - 1
+            })
+            .collect::<Vec<(i32, i32)>>();
+        log::trace!("neighbours: {:?}", path);
+        path
+    }
+}
+
+// solution 1
+pub fn solution_1(map: &Grid<char>) -> Result<i32, ParseError> {
+    let (starting_pos_x, starting_pos_y, _, _) = find_positions(map);
+    let mut queue = VecDeque::<Position>::new();
+    queue.push_back(Position::new(starting_pos_x, starting_pos_y, 0));
+    let mut visited = HashMap::<(i32, i32), i32>::new();
+    visited.insert((starting_pos_x, starting_pos_y), 0);
+
+    while !queue.is_empty() {
+        let current = queue.pop_front().unwrap();
+        log::trace!("current: {:?}", current);
+        for neighbour in current.get_neighbours(map) {
+            let neighbour_elavation = map[neighbour];
+            if neighbour_elavation == 'E' {
+                return Ok(current.steps + 1);
+            }
+
+            // if path found have a took a lesser step to reached. dont go there anymore.
+            if let Some(steps) = visited.get(&neighbour) {
+                if *steps <= current.steps + 1 {
+                    continue;
+                }
+            }
+
+            let new_pos = Position::new(neighbour.0, neighbour.1, current.steps + 1);
+            queue.push_back(new_pos);
+            visited.insert((neighbour.0, neighbour.1), current.steps + 1);
+        }
+    }
+    Err(no_path(map, "a path from S to E"))
+}
+
+pub fn solution_2(map: &Grid<char>) -> Result<i32, ParseError> {
+    let (_, _, ending_pos_x, ending_pos_y) = find_positions(map);
+    let mut queue = VecDeque::<Position>::new();
+    queue.push_back(Position::new(ending_pos_x, ending_pos_y, 0));
+    let mut visited = HashMap::<(i32, i32), i32>::new();
+    visited.insert((ending_pos_x, ending_pos_y), 0);
+
+    while !queue.is_empty() {
+        let current = queue.pop_front().unwrap();
+        log::trace!("current: {:?}", current);
+        for neighbour in current.get_path_from(map) {
+            let neighbour_elavation = update_elavation(map[neighbour]);
+            if neighbour_elavation == 'a' {
+                return Ok(current.steps + 1);
+            }
+
+            // if path found have a took a lesser step to reached. dont go there anymore.
+            if let Some(steps) = visited.get(&(neighbour.0, neighbour.1)) {
+                if *steps <= current.steps + 1 {
+                    continue;
+                }
+            }
+
+            let new_pos = Position::new(neighbour.0, neighbour.1, current.steps + 1);
+            queue.push_back(new_pos);
+            visited.insert((neighbour.0, neighbour.1), current.steps + 1);
+        }
+    }
+    Err(no_path(map, "a path from a square of elevation a to E"))
+}
+
+// error at E, which the climb could not reach
+fn no_path(map: &Grid<char>, expected: &str) -> ParseError {
+    let (_, _, x, y) = find_positions(map);
+    ParseError::new(y as usize + 1, x as usize + 1, expected)
+}
+
+fn find_positions(map: &Grid<char>) -> (i32, i32, i32, i32) {
+    let starting_pos = map.position(|&c| c == 'S').unwrap_or((-1, -1));
+    let ending_pos = map.position(|&c| c == 'E').unwrap_or((-1, -1));
+
+    (starting_pos.0, starting_pos.1, ending_pos.0, ending_pos.1)
+}
+
+fn update_elavation(elavation: char) -> char {
+    if elavation == 'S' {
+        'a'
+    } else if elavation == 'E' {
+        'z'
+    } else {
+        elavation
+    }
+}
+
+// every row has to be as wide as the first one, with exactly one S and E
+pub fn load_map(input: &str) -> Result<Grid<char>, ParseError> {
+    let map = Grid::parse(input, "an elevation", |c| {
+        matches!(c, 'a'..='z' | 'S' | 'E').then_some(c)
+    })?;
+    for marker in ['S', 'E'] {
+        let mut squares = map.iter().filter(|(_, &c)| c == marker);
+        if squares.next().is_none() {
+            return Err(ParseError::at(
+                input,
+                &input[input.len()..],
+                "a square marked S and E",
+            ));
+        }
+        if let Some(((x, y), _)) = squares.next() {
+            let (line, column) = (y as usize + 1, x as usize + 1);
+            return Err(ParseError::new(line, column, "a single S and E"));
+        }
+    }
+    Ok(map)
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?)?.to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?)?.to_string())
+}
+
+// heightmap rows
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    load_map(input)
+}
+
+// fewest steps from S to E, an error when E cannot be reached
+pub fn solve_part1(map: &Grid<char>) -> Result<i32, ParseError> {
+    solution_1(map)
+}
+
+// fewest steps from any lowest square to E, an error when none reaches it
+pub fn solve_part2(map: &Grid<char>) -> Result<i32, ParseError> {
+    solution_2(map)
+}
+
+// size by size map, at least 27 wide to fit the climb. one row climbs a
+// letter at a time from S to E, so there is always a path
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(27);
+    let height = size.max(1);
+    let ramp = rng.index(height);
+    let mut output = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let c = match (y == ramp, x) {
+                (true, 0) => 'S',
+                (true, x) if x == width - 1 => 'E',
+                (true, x) => (b'a' + (x - 1).min(25) as u8) as char,
+                (false, _) => (b'a' + rng.index(26) as u8) as char,
+            };
+            output.push(c);
+        }
+        output.push('\n');
+    }
+    output
+}
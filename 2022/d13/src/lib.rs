@@ -0,0 +1,153 @@
+use std::cmp::Ordering;
+
+use aoc_common::{ParseError, Rng};
+use serde_json::Value;
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// every non-empty line as a json packet
+pub fn parse(input: &str) -> Result<Vec<Value>, ParseError> {
+    load_packet(input)
+}
+
+// sum of the indices of pairs already in the right order
+pub fn solve_part1(packets: &[Value]) -> usize {
+    let mut packets_iter = packets.iter();
+
+    let mut counter = 0;
+    let mut solution_1_ans = 0;
+    while let Some(left_value) = packets_iter.next() {
+        counter += 1;
+        let right_value = packets_iter.next().unwrap();
+        let result = compare_packets(left_value, right_value);
+        if result == Ordering::Less {
+            solution_1_ans += counter;
+        }
+    }
+    solution_1_ans
+}
+
+// decoder key once the divider packets are sorted in
+pub fn solve_part2(packets: &[Value]) -> usize {
+    let mut packets = packets.to_vec();
+
+    // create new serde value
+    // [[2]]
+    // [[6]]
+    let new_dividers: Vec<Value> = vec![
+        serde_json::from_str("[[2]]").unwrap(),
+        serde_json::from_str("[[6]]").unwrap(),
+    ];
+    packets.extend(new_dividers.clone());
+    packets.sort_by(compare_packets);
+    let index_1 = packets.iter().position(|x| x == &new_dividers[0]).unwrap() + 1;
+    let index_2 = packets.iter().position(|x| x == &new_dividers[1]).unwrap() + 1;
+    index_2 * index_1
+}
+
+pub fn compare_packets(left: &Value, right: &Value) -> Ordering {
+    // if left and right are both array
+    // if left or right is array
+    // if left and right are number
+    // else panic
+
+    if left.is_array() && right.is_array() {
+        let l = left.as_array().unwrap();
+        let r = right.as_array().unwrap();
+
+        for (l_elem, r_elem) in l.iter().zip(r.iter()) {
+            let result = compare_packets(l_elem, r_elem);
+            if result != Ordering::Equal {
+                return result;
+            }
+        }
+        l.len().cmp(&r.len())
+    } else if left.is_array() || right.is_array() {
+        let l = convert_to_value(left);
+        let r = convert_to_value(right);
+        compare_packets(&l, &r)
+    } else if left.is_number() && right.is_number() {
+        let l = left.as_i64().unwrap();
+        let r = right.as_i64().unwrap();
+        l.cmp(&r)
+    } else {
+        panic!();
+    }
+}
+
+// convert int into Value of array
+fn convert_to_value(value: &Value) -> Value {
+    if value.is_array() {
+        value.clone()
+    } else {
+        Value::Array(vec![value.clone()])
+    }
+}
+
+// packets come in pairs, each one a list holding integers and lists
+pub fn load_packet(input: &str) -> Result<Vec<Value>, ParseError> {
+    let mut packets = Vec::new();
+    for line in input.lines() {
+        if line.is_empty() {
+            continue;
+        }
+        let packet: Value = match serde_json::from_str(line) {
+            Ok(packet) => packet,
+            Err(err) => {
+                // serde counts columns from 1 and reports 0 at the very start
+                let column = err.column().saturating_sub(1).min(line.len());
+                let start = line
+                    .char_indices()
+                    .nth(column)
+                    .map_or(line.len(), |(i, _)| i);
+                return Err(ParseError::at(input, &line[start..], "a packet"));
+            }
+        };
+        if !packet.is_array() || !is_valid_packet(&packet) {
+            return Err(ParseError::at(input, line, "a list of integers and lists"));
+        }
+        packets.push(packet)
+    }
+    if packets.len() % 2 == 1 {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "a second packet",
+        ));
+    }
+    Ok(packets)
+}
+
+fn is_valid_packet(value: &Value) -> bool {
+    match value {
+        Value::Array(list) => list.iter().all(is_valid_packet),
+        Value::Number(number) => number.is_i64(),
+        _ => false,
+    }
+}
+
+// size pairs of packets nested up to three lists deep
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let pairs = (0..size.max(1))
+        .map(|_| format!("{}\n{}\n", generate_packet(rng, 0), generate_packet(rng, 0)))
+        .collect::<Vec<_>>();
+    pairs.join("\n")
+}
+
+fn generate_packet(rng: &mut Rng, depth: usize) -> String {
+    let mut items = Vec::new();
+    for _ in 0..rng.range(0, 4) {
+        let item = match depth < 3 && rng.one_in(3) {
+            true => generate_packet(rng, depth + 1),
+            false => rng.range(0, 10).to_string(),
+        };
+        items.push(item);
+    }
+    format!("[{}]", items.join(","))
+}
@@ -0,0 +1,333 @@
+use std::cmp::{max, min};
+
+use aoc_common::{next_token, parse_token, Frame, FrameSink, Grid, ParseError, Rng};
+
+// sand = 0
+// rock = #
+// none = .
+#[derive(PartialEq, Debug, Clone)]
+enum GameObject {
+    Sand,
+    Rock,
+    None,
+}
+
+struct Sand {
+    x: i32,
+    y: i32,
+}
+
+impl Sand {
+    fn new(x: i32, y: i32) -> Sand {
+        Sand { x, y }
+    }
+
+    fn get_final_pos(&mut self, game: &Game) -> (bool, i32, i32) {
+        loop {
+            if game.is_out_of_bound(self.x, self.y) {
+                return (false, self.x, self.y);
+            }
+
+            if game.is_free(self.move_down()) {
+                (self.x, self.y) = self.move_down();
+            } else if game.is_free(self.move_bottom_left()) {
+                (self.x, self.y) = self.move_bottom_left();
+            } else if game.is_free(self.move_bottom_right()) {
+                (self.x, self.y) = self.move_bottom_right();
+            } else {
+                break;
+            }
+        }
+
+        (true, self.x, self.y)
+    }
+
+    fn get_final_pos_v2(&mut self, game: &Game) -> (bool, i32, i32) {
+        loop {
+            if game.is_free(self.move_down()) {
+                (self.x, self.y) = self.move_down();
+            } else if game.is_free(self.move_bottom_right()) {
+                (self.x, self.y) = self.move_bottom_right();
+            } else if game.is_free(self.move_bottom_left()) {
+                (self.x, self.y) = self.move_bottom_left();
+            } else {
+                break;
+            }
+
+            if self.reach_fixed_wall((self.x, self.y), game.y_coord.1) {
+                break;
+            }
+        }
+
+        (true, self.x, self.y)
+    }
+
+    fn reach_fixed_wall(&self, coord: (i32, i32), max_y: i32) -> bool {
+        coord.1 > max_y
+    }
+
+    fn move_bottom_left(&self) -> (i32, i32) {
+        (self.x - 1, self.y + 1)
+    }
+
+    fn move_bottom_right(&self) -> (i32, i32) {
+        (self.x + 1, self.y + 1)
+    }
+
+    fn move_down(&self) -> (i32, i32) {
+        (self.x, self.y + 1)
+    }
+}
+
+struct InitBlockResponse {
+    block_list: Vec<(i32, i32)>,
+    x_coord: (i32, i32),
+    y_coord: (i32, i32),
+}
+
+// 2d map, wide enough for the pile of sand on the floor. grid column 0 is
+// x_offset on the cave's x axis
+#[derive(Clone)]
+pub struct Game {
+    block_map: Grid<GameObject>,
+    x_offset: i32,
+    x_coord: (i32, i32),
+    y_coord: (i32, i32),
+}
+
+// sand goes all the way down
+// if no path, go diagonal left (down + left)
+// else go diagonal right (down + right)
+// take note! the higher the y value, the lower the position
+
+impl Game {
+    fn new(input: &str) -> Result<Game, ParseError> {
+        let init_block_response = initialise_blocks(input)?;
+        let block_list_to_add = init_block_response.block_list;
+        let x_coord = init_block_response.x_coord;
+        let y_coord = init_block_response.y_coord;
+        // sand piles up from 500 at most one column further per row down to the floor
+        let floor = y_coord.1 + 2;
+        let x_offset = min(x_coord.0, 500 - floor) - 1;
+        let width = max(x_coord.1, 500 + floor) + 2 - x_offset;
+        // initialise map with block_list
+        let mut block_map = Grid::new(width as usize, floor as usize + 1, GameObject::None);
+        for block in block_list_to_add {
+            block_map[(block.0 - x_offset, block.1)] = GameObject::Rock;
+        }
+        Ok(Game {
+            block_map,
+            x_offset,
+            x_coord,
+            y_coord,
+        })
+    }
+
+    fn is_free(&self, (x, y): (i32, i32)) -> bool {
+        !matches!(
+            self.block_map.get((x - self.x_offset, y)),
+            Some(GameObject::Sand | GameObject::Rock)
+        )
+    }
+
+    // where the sand comes to rest, None once it no longer does
+    fn add_sand(&mut self, x: i32) -> Option<(i32, i32)> {
+        let nxt_x = x;
+        let nxt_y = 0;
+        // rocks can also catch all of the sand until it blocks the source
+        if !self.is_free((x, 0)) {
+            return None;
+        }
+        let mut sand = Sand::new(nxt_x, nxt_y);
+        let (is_poss, nxt_x, nxt_y) = sand.get_final_pos(self);
+        if !is_poss {
+            return None;
+        }
+
+        self.block_map[(nxt_x - self.x_offset, nxt_y)] = GameObject::Sand;
+        Some((nxt_x, nxt_y))
+    }
+
+    fn add_sand_v2(&mut self, x: i32) -> Option<(i32, i32)> {
+        let nxt_x = x;
+        let nxt_y = 0;
+        if !self.is_free((x, 0)) {
+            return None;
+        }
+        let mut sand = Sand::new(nxt_x, nxt_y);
+        let (is_poss, nxt_x, nxt_y) = sand.get_final_pos_v2(self);
+        if !is_poss {
+            return None;
+        }
+
+        self.block_map[(nxt_x - self.x_offset, nxt_y)] = GameObject::Sand;
+        Some((nxt_x, nxt_y))
+    }
+
+    fn is_out_of_bound(&self, x: i32, y: i32) -> bool {
+        x < self.x_coord.0 || x > self.x_coord.1 || y < self.y_coord.0 || y > self.y_coord.1
+    }
+
+    // rock and sand in cave coordinates, with the source at 500,0. the
+    // floor of part two is left out, it is as wide as the map
+    fn get_frame(&self) -> Frame {
+        let mut frame = Frame::new();
+        frame.set((500, 0), '+');
+        for ((x, y), object) in self.block_map.iter() {
+            match object {
+                GameObject::Sand => frame.set((x + self.x_offset, y), 'o'),
+                GameObject::Rock => frame.set((x + self.x_offset, y), '#'),
+                GameObject::None => {}
+            }
+        }
+        frame
+    }
+}
+
+fn initialise_blocks(input: &str) -> Result<InitBlockResponse, ParseError> {
+    let mut x_coord = (500, 500);
+    let mut y_coord = (0, 0);
+
+    let mut block_list_to_return = Vec::new();
+    for block_str in input.lines() {
+        let mut starting_block: Option<(i32, i32)> = None;
+        for block in block_str.split("->") {
+            let block = block.trim();
+            // convert string to i32
+            let mut coord = block.split(',');
+            let x = next_token(input, block, &mut coord, "an x coordinate")?;
+            let y = next_token(input, block, &mut coord, "a y coordinate")?;
+            // the cave starts at the sand source, there is nothing above it
+            let coord = [
+                parse_token::<i32>(input, x, "an x coordinate")?,
+                parse_token::<u16>(input, y, "a y coordinate")? as i32,
+            ];
+
+            if let Some(starting_block) = starting_block {
+                if starting_block.0 != coord[0] && starting_block.1 != coord[1] {
+                    return Err(ParseError::at(
+                        input,
+                        block,
+                        "a point in line with the previous one",
+                    ));
+                }
+                for x in min(starting_block.0, coord[0])..=max(starting_block.0, coord[0]) {
+                    block_list_to_return.push((x, coord[1]));
+                }
+                for y in min(starting_block.1, coord[1])..=max(starting_block.1, coord[1]) {
+                    block_list_to_return.push((coord[0], y));
+                }
+            }
+
+            block_list_to_return.push((coord[0], coord[1]));
+            starting_block = Some((coord[0], coord[1]));
+        }
+    }
+
+    for item in block_list_to_return.iter() {
+        x_coord.0 = min(x_coord.0, item.0);
+        x_coord.1 = max(x_coord.1, item.0);
+        y_coord.0 = min(y_coord.0, item.1);
+        y_coord.1 = max(y_coord.1, item.1);
+    }
+
+    Ok(InitBlockResponse {
+        block_list: block_list_to_return,
+        x_coord,
+        y_coord,
+    })
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// cave with every rock path laid out
+pub fn parse(input: &str) -> Result<Game, ParseError> {
+    Game::new(input)
+}
+
+// units of sand at rest before sand falls into the abyss
+pub fn solve_part1(game: &Game) -> i32 {
+    let mut game = game.clone();
+    let mut counter = 0;
+    while game.add_sand(500).is_some() {
+        counter += 1;
+    }
+    counter
+}
+
+// units of sand at rest once the source is blocked
+pub fn solve_part2(game: &Game) -> i32 {
+    let mut game = game.clone();
+    let mut counter = 0;
+    while game.add_sand_v2(500).is_some() {
+        counter += 1;
+    }
+    counter
+}
+
+// one frame per unit of sand coming to rest, following it as the pile grows
+pub fn replay(game: &Game, part: u8, sink: &mut dyn FrameSink) {
+    let mut game = game.clone();
+    let mut counter = 0;
+    let mut last = None;
+    loop {
+        let added = match part {
+            1 => game.add_sand(500),
+            _ => game.add_sand_v2(500),
+        };
+        let position = match added {
+            Some(position) => position,
+            None => break,
+        };
+        counter += 1;
+        last = Some(position);
+        if sink.wants_frame() {
+            let mut frame = game.get_frame();
+            frame.focus_on(position);
+            frame.set_caption(format!("sand at rest: {}", counter));
+            sink.frame(&frame);
+        }
+    }
+    if sink.final_only() {
+        let mut frame = game.get_frame();
+        if let Some(position) = last {
+            frame.focus_on(position);
+        }
+        frame.set_caption(format!("sand at rest: {}", counter));
+        sink.frame(&frame);
+    }
+}
+
+// size rock paths scattered below the sand source, free to cross and
+// overlap one another
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let spread = 5 + size as i64 * 2;
+    let mut output = String::new();
+    for _ in 0..size.max(1) {
+        let mut point = (
+            rng.range(500 - spread, 500 + spread),
+            rng.range(2, 2 + spread),
+        );
+        let mut points = vec![point];
+        for _ in 0..rng.range(1, 4) {
+            let length = rng.range(1, 6) * if rng.one_in(2) { 1 } else { -1 };
+            point = match rng.one_in(2) {
+                true => (point.0 + length, point.1),
+                false => (point.0, (point.1 + length).max(1)),
+            };
+            points.push(point);
+        }
+        let path = points
+            .iter()
+            .map(|(x, y)| format!("{},{}", x, y))
+            .collect::<Vec<_>>();
+        output += &format!("{}\n", path.join(" -> "));
+    }
+    output
+}
@@ -0,0 +1,302 @@
+use std::{
+    cmp::max,
+    collections::{HashMap, HashSet},
+    error::Error,
+};
+
+use aoc_common::{parse_token, ParseError, Rng};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone)]
+pub struct Sensor {
+    coord: (i32, i32),
+    beacon: (i32, i32),
+}
+
+impl Sensor {
+    fn new(coord: (i32, i32), beacon: (i32, i32)) -> Sensor {
+        Sensor { coord, beacon }
+    }
+
+    fn get_dist(&self) -> i32 {
+        (self.coord.0 - self.beacon.0).abs() + (self.coord.1 - self.beacon.1).abs()
+    }
+
+    fn covers(&self, position: (i32, i32)) -> bool {
+        (self.coord.0 - position.0).abs() + (self.coord.1 - position.1).abs() <= self.get_dist()
+    }
+
+    fn optimised_run(&self, y: i32) -> HashSet<(i32, i32)> {
+        let mut visited = HashSet::<(i32, i32)>::new();
+        let dist = self.get_dist() - (self.coord.1 - y).abs();
+        let start = self.coord.0 - dist;
+        let end = self.coord.0 + dist;
+        for i in start..=end {
+            visited.insert((i, y));
+        }
+
+        visited
+    }
+
+    fn get_range(&self, y: i32) -> Option<(i32, i32)> {
+        let dist = self.get_dist() - (self.coord.1 - y).abs();
+        if dist < 0 {
+            return None;
+        }
+        let start = self.coord.0 - dist;
+        let end = self.coord.0 + dist;
+        Some((start, end))
+    }
+}
+
+struct Game {
+    sensor_list: Vec<Sensor>,
+    game_map: HashMap<(i32, i32), i32>,
+}
+
+impl Game {
+    fn new(sensor_list: Vec<Sensor>) -> Game {
+        let mut game_map = HashMap::<(i32, i32), i32>::new();
+        for sensor in &sensor_list {
+            game_map.insert((sensor.coord.0, sensor.coord.1), 3);
+        }
+        // a beacon right on a sensor is still a beacon
+        for sensor in &sensor_list {
+            game_map.insert((sensor.beacon.0, sensor.beacon.1), 2);
+        }
+
+        Game {
+            sensor_list,
+            game_map,
+        }
+    }
+
+    fn run_v1(&mut self, y: i32) -> i32 {
+        let mut hashset = HashSet::<i32>::new();
+        for sensor in &self.sensor_list {
+            if y <= sensor.coord.1 + sensor.get_dist() && y >= sensor.coord.1 - sensor.get_dist() {
+                let temp = sensor.optimised_run(y);
+                for (i, j) in temp {
+                    // a known beacon is the one position that does hold a
+                    // beacon, a sensor's own position is covered like any other
+                    match self.game_map.get(&(i, j)) {
+                        Some(2) => continue,
+                        Some(_) => {}
+                        None => {
+                            self.game_map.insert((i, j), 1);
+                        }
+                    }
+                    if j == y {
+                        hashset.insert(i);
+                    }
+                }
+            }
+        }
+        hashset.len() as i32
+    }
+
+    // return the tuning frequency of the only position not covered within
+    // 0..=bound, if there is one. rows are scanned in parallel when there is a thread pool,
+    // the first row with a gap wins whichever thread finds it
+    fn run_v2(&self, bound: i32) -> Option<i64> {
+        let start = 0;
+
+        let gap = aoc_common::parallel(|| {
+            (start..=bound).into_par_iter().find_map_first(|i| {
+                let mut sorted_range = Vec::<(i32, i32)>::new();
+                for sensor in &self.sensor_list {
+                    if let Some(range) = sensor.get_range(i) {
+                        sorted_range.push(range);
+                    }
+                }
+                sorted_range.sort();
+                // every position of the row from 0 up to covered is covered, gaps
+                // outside the search area do not count
+                let mut covered = -1;
+                for next_range in sorted_range {
+                    if next_range.0 > covered + 1 {
+                        break;
+                    }
+                    covered = max(covered, next_range.1);
+                }
+                (covered < bound).then_some((covered + 1, i))
+            })
+        });
+
+        gap.map(|(x, y)| get_solution_2(x, y))
+    }
+}
+
+pub fn load_sensor_list(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    let mut sensor_list = Vec::<Sensor>::new();
+
+    for line in input.lines() {
+        let splitted = line.split(' ').collect::<Vec<&str>>();
+
+        // x=2, y=18: for the sensor and x=-2, y=15 for the beacon
+        let pattern = ['x', 'y', ':', ',', '='];
+        let mut coords = [0; 4];
+        for (coord, index) in coords.iter_mut().zip([2, 3, 8, 9]) {
+            let expected = if index % 2 == 0 {
+                "x=<number>"
+            } else {
+                "y=<number>"
+            };
+            let token = match splitted.get(index) {
+                Some(token) => token.trim_matches(pattern),
+                None => return Err(ParseError::at(input, &line[line.len()..], expected)),
+            };
+            *coord = parse_token::<i32>(input, token, expected)?;
+        }
+        let [sensor_x, sensor_y, beacon_x, beacon_y] = coords;
+        let sensor = Sensor::new((sensor_x, sensor_y), (beacon_x, beacon_y));
+        sensor_list.push(sensor);
+    }
+
+    if sensor_list.is_empty() {
+        return Err(ParseError::new(1, 1, "a sensor"));
+    }
+    Ok(sensor_list)
+}
+
+fn get_solution_2(x: i32, y: i32) -> i64 {
+    x as i64 * 4000000 + y as i64
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+// an error when no position is left uncovered
+pub fn part2(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(solve_part2(&parse(input)?)?.to_string())
+}
+
+// sensors with their closest beacon
+pub fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    load_sensor_list(input)
+}
+
+// the row part one looks at and the side of the search area of part two,
+// the examples use 10 and 20
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub row: i32,
+    pub bound: i32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            row: 2000000,
+            bound: 4000000,
+        }
+    }
+}
+
+pub fn solve_part1(sensor_list: &[Sensor]) -> i32 {
+    solve_part1_with(sensor_list, &Params::default())
+}
+
+pub fn solve_part2(sensor_list: &[Sensor]) -> Result<i64, String> {
+    solve_part2_with(sensor_list, &Params::default())
+}
+
+pub fn solve_part1_with(sensor_list: &[Sensor], params: &Params) -> i32 {
+    solution_1(sensor_list, params.row)
+}
+
+// an error when a smaller bound leaves no position uncovered
+pub fn solve_part2_with(sensor_list: &[Sensor], params: &Params) -> Result<i64, String> {
+    solution_2(sensor_list, params.bound)
+        .ok_or_else(|| format!("no position within 0..={} is left uncovered", params.bound))
+}
+
+// number of positions in row y where a beacon cannot be present
+pub fn solution_1(sensor_list: &[Sensor], y: i32) -> i32 {
+    let mut game = Game::new(sensor_list.to_vec());
+    game.run_v1(y)
+}
+
+pub fn solution_2(sensor_list: &[Sensor], bound: i32) -> Option<i64> {
+    let game = Game::new(sensor_list.to_vec());
+    game.run_v2(bound)
+}
+
+// slow but obviously right version of solution_1 that checks every position
+// of the row against every sensor, to test the fast one on small inputs
+pub fn naive_solution_1(sensor_list: &[Sensor], y: i32) -> i32 {
+    let start = sensor_list.iter().map(|s| s.coord.0 - s.get_dist()).min();
+    let end = sensor_list.iter().map(|s| s.coord.0 + s.get_dist()).max();
+    let (Some(start), Some(end)) = (start, end) else {
+        return 0;
+    };
+    (start..=end)
+        .filter(|&x| {
+            sensor_list.iter().all(|s| s.beacon != (x, y))
+                && sensor_list.iter().any(|s| s.covers((x, y)))
+        })
+        .count() as i32
+}
+
+// slow version of solution_2 that checks every position of the search area,
+// returning the first one no sensor covers
+pub fn naive_solution_2(sensor_list: &[Sensor], bound: i32) -> Option<i64> {
+    for y in 0..=bound {
+        for x in 0..=bound {
+            if !sensor_list.iter().any(|s| s.covers((x, y))) {
+                return Some(get_solution_2(x, y));
+            }
+        }
+    }
+    None
+}
+
+// eight sensors that close in on the distress beacon from every side and
+// diagonal while leaving it uncovered, and size more that stay clear of it.
+// the beacon is within the top quarter of the search area, which keeps the
+// ranges on the row of part one about as wide as in a real input
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let distress = (rng.range(0, 4000000) as i32, rng.range(0, 1000000) as i32);
+    // every row from the top of the area down to the beacon is within reach
+    let reach = distress.1 + rng.range(4, 100000) as i32;
+    let mut sensor_list = Vec::new();
+    for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+        let coord = (distress.0 + dx * reach, distress.1 + dy * reach);
+        let beacon = (distress.0 + dx, distress.1 + dy);
+        sensor_list.push(Sensor::new(coord, beacon));
+    }
+    for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let coord = (distress.0 + dx * reach, distress.1 + dy * reach);
+        let beacon = (distress.0 + dx, distress.1);
+        sensor_list.push(Sensor::new(coord, beacon));
+    }
+    for _ in 0..size {
+        // within half the reach a sensor cannot cover past the others
+        let dist = rng.range(2, (reach as i64 / 2).max(2)) as i32;
+        let coord = offset(rng, distress, dist);
+        let radius = rng.range(1, dist as i64 - 1) as i32;
+        let beacon = offset(rng, coord, radius);
+        sensor_list.push(Sensor::new(coord, beacon));
+    }
+    rng.shuffle(&mut sensor_list);
+
+    sensor_list
+        .iter()
+        .map(|sensor| {
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                sensor.coord.0, sensor.coord.1, sensor.beacon.0, sensor.beacon.1
+            )
+        })
+        .collect()
+}
+
+// random position dist steps away from from
+fn offset(rng: &mut Rng, from: (i32, i32), dist: i32) -> (i32, i32) {
+    let dx = rng.range(-dist as i64, dist as i64) as i32;
+    let dy = (dist - dx.abs()) * if rng.one_in(2) { 1 } else { -1 };
+    (from.0 + dx, from.1 + dy)
+}
@@ -0,0 +1,489 @@
+use std::{
+    cmp::max,
+    collections::{BTreeMap, BTreeSet, HashMap},
+};
+
+use aoc_common::{parse_token, ParseError, Rng};
+use serde::{Deserialize, Serialize};
+
+// declare global inf
+const INF: i64 = 1 << 30;
+
+// valves with a flow rate that fit in a Bitmask, the others are never opened
+const MAX_FLOWING: usize = 64;
+
+// one bit per valve with a flow rate, in the order of their index
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Copy, Ord, PartialOrd, Default)]
+struct Bitmask {
+    mask: u64,
+}
+
+pub struct TunnelMap {
+    name_to_index_mapper: BTreeMap<String, i32>,
+    #[allow(dead_code)]
+    index_to_name_mapper: BTreeMap<i32, String>,
+    // name_to_profit_mapper: HashMap<String, i32>,
+    index_to_profit_mapper: BTreeMap<i32, i32>,
+    // bit of every valve with a flow rate in a Bitmask
+    index_to_bit_mapper: BTreeMap<i32, u32>,
+    tunnel_map: Vec<Vec<i64>>,
+    highest_index: i32, // use for generating index for new valve
+}
+
+impl TunnelMap {
+    fn new(size: i32) -> Self {
+        // initialise with map with size
+        let tunnel_map = vec![vec![INF; size as usize]; size as usize];
+        let highest_index = 0;
+        Self {
+            name_to_index_mapper: BTreeMap::new(),
+            index_to_name_mapper: BTreeMap::new(),
+            index_to_profit_mapper: BTreeMap::new(),
+            index_to_bit_mapper: BTreeMap::new(),
+            tunnel_map,
+            highest_index,
+        }
+    }
+
+    fn get_index_list(&self) -> Vec<usize> {
+        self.index_to_profit_mapper
+            .keys()
+            .map(|k| *k as usize)
+            .collect()
+    }
+
+    fn preculculate_distance(&mut self) {
+        let size = self.tunnel_map.len();
+        // staying at a valve takes no time, so AA can be opened first
+        for i in 0..size {
+            self.tunnel_map[i][i] = 0;
+        }
+
+        for k in 0..size {
+            for i in 0..size {
+                for j in 0..size {
+                    if self.tunnel_map[i][j] > self.tunnel_map[i][k] + self.tunnel_map[k][j] {
+                        self.tunnel_map[i][j] = self.tunnel_map[i][k] + self.tunnel_map[k][j];
+                    }
+                }
+            }
+        }
+    }
+
+    fn assign_bits(&mut self) {
+        let flowing = self
+            .index_to_profit_mapper
+            .iter()
+            .filter(|(_, profit)| **profit > 0)
+            .map(|(index, _)| *index)
+            .collect::<Vec<_>>();
+        for (bit, index) in flowing.into_iter().enumerate() {
+            self.index_to_bit_mapper.insert(index, bit as u32);
+        }
+    }
+
+    fn get_bit(&self, index: usize) -> Option<u32> {
+        self.index_to_bit_mapper.get(&(index as i32)).copied()
+    }
+
+    fn get_profit(&self, index: usize) -> i32 {
+        *self.index_to_profit_mapper.get(&(index as i32)).unwrap()
+    }
+
+    fn get_time_on_travel(&self, from: Option<i32>, to: usize) -> i32 {
+        if let Some(from) = from {
+            self.tunnel_map[from as usize][to] as i32
+        } else {
+            0
+        }
+    }
+
+    fn get_neighbour(&self, index: i32) -> Vec<usize> {
+        self.tunnel_map
+            .get(index as usize)
+            .unwrap()
+            .iter()
+            .enumerate()
+            .filter(|(k, v)| {
+                **v != INF && self.index_to_profit_mapper.get(&(*k as i32)).unwrap() > &0
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn add_valve(&mut self, name: String, profit: i32, neighbour: Vec<String>) {
+        let current_valve_index = self.get_valve_index(name, Some(profit));
+        for neighbour in neighbour {
+            let neighbour_index = self.get_valve_index(neighbour, None);
+            self.tunnel_map[current_valve_index as usize][neighbour_index as usize] = 1;
+        }
+    }
+
+    fn find_valve_index(&self, name: &str) -> i32 {
+        *self.name_to_index_mapper.get(name).unwrap()
+    }
+
+    fn get_valve_index(&mut self, name: String, profit: Option<i32>) -> i32 {
+        if !self.name_to_index_mapper.contains_key(&name) {
+            self.name_to_index_mapper
+                .insert(name.clone(), self.highest_index);
+            self.index_to_name_mapper
+                .insert(self.highest_index, name.clone());
+
+            self.highest_index += 1;
+        }
+
+        let index_to_return = *self.name_to_index_mapper.get(&name).unwrap();
+        if let Some(profit) = profit {
+            self.index_to_profit_mapper.insert(index_to_return, profit);
+        }
+        index_to_return
+    }
+}
+
+impl Bitmask {
+    fn set(&mut self, bit: u32) {
+        self.mask |= 1 << bit;
+    }
+
+    fn get(&self, bit: u32) -> bool {
+        (self.mask & 1 << bit) != 0
+    }
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// valves with the shortest travel time between every pair
+pub fn parse(input: &str) -> Result<TunnelMap, ParseError> {
+    load_input(input)
+}
+
+// minutes before the volcano erupts, alone and with the elephant after
+// teaching it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub minutes: i32,
+    pub minutes_with_elephant: i32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            minutes: 30,
+            minutes_with_elephant: 26,
+        }
+    }
+}
+
+pub fn solve_part1(game: &TunnelMap) -> i32 {
+    solve_part1_with(game, &Params::default())
+}
+
+pub fn solve_part2(game: &TunnelMap) -> i32 {
+    solve_part2_with(game, &Params::default())
+}
+
+// most pressure released alone
+pub fn solve_part1_with(game: &TunnelMap, params: &Params) -> i32 {
+    let visiting_index = game.find_valve_index("AA");
+    let visited = Bitmask::default();
+    let mut memo = BTreeMap::<Bitmask, i32>::new();
+    calculate_max_profit_2(
+        game,
+        Some(visiting_index),
+        &visited,
+        &mut memo,
+        params.minutes,
+        0,
+    )
+}
+
+// most pressure released with an elephant
+pub fn solve_part2_with(game: &TunnelMap, params: &Params) -> i32 {
+    let visiting_index = game.find_valve_index("AA");
+
+    let mut solution2_memo1 = BTreeMap::<Bitmask, i32>::new();
+
+    // path you opened
+    let new_visited = Bitmask::default();
+    calculate_max_profit_2(
+        game,
+        Some(visiting_index),
+        &new_visited,
+        &mut solution2_memo1,
+        params.minutes_with_elephant,
+        0,
+    );
+
+    let mut ans = 0;
+    log::debug!("solution 2 memo len: {:?}", solution2_memo1.len());
+
+    for (key1, value1) in &solution2_memo1 {
+        let mask_1 = key1.mask;
+        for (key2, value2) in &solution2_memo1 {
+            let mask_2 = key2.mask;
+
+            if mask_1 & mask_2 == 0 {
+                ans = max(ans, value1 + value2);
+            }
+        }
+    }
+    ans
+}
+
+// at most MAX_FLOWING valves may have a flow rate
+pub fn load_input(input: &str) -> Result<TunnelMap, ParseError> {
+    let lines = input.lines();
+    let mut game_2 = TunnelMap::new(lines.clone().count() as i32);
+
+    let mut valve_list = Vec::new();
+    let mut flowing = 0;
+    for line in lines {
+        let splitted: Vec<&str> = line.split_whitespace().collect();
+        // from 9 to end
+        if splitted.len() < 10 {
+            let expected = "Valve XX has flow rate=N; tunnels lead to valves ...";
+            return Err(ParseError::at(input, &line[line.len()..], expected));
+        }
+        let from_valve = splitted[1];
+        let rate = splitted[4]
+            .trim_start_matches("rate=")
+            .trim_end_matches(';');
+        let profit = parse_token::<i32>(input, rate, "a flow rate")?;
+        if profit > 0 {
+            flowing += 1;
+            if flowing > MAX_FLOWING {
+                let expected = format!("at most {} valves with a flow rate", MAX_FLOWING);
+                return Err(ParseError::at(input, rate, expected));
+            }
+        }
+        let to_valve_list: Vec<&str> = splitted[9..]
+            .iter()
+            .map(|e| e.trim_end_matches(','))
+            .collect();
+        valve_list.push((from_valve, profit, to_valve_list));
+    }
+
+    // a tunnel to a valve without its own line would fall outside the map
+    let valve_names = valve_list
+        .iter()
+        .map(|(name, _, _)| *name)
+        .collect::<BTreeSet<&str>>();
+    if !valve_names.contains("AA") {
+        return Err(ParseError::new(1, 1, "a line for valve AA"));
+    }
+    for (from_valve, profit, to_valve_list) in valve_list {
+        if let Some(unknown) = to_valve_list.iter().find(|e| !valve_names.contains(*e)) {
+            return Err(ParseError::at(input, unknown, "a valve with its own line"));
+        }
+        let to_valve_list = to_valve_list.iter().map(|e| e.to_string()).collect();
+        game_2.add_valve(from_valve.to_string(), profit, to_valve_list);
+    }
+
+    game_2.preculculate_distance();
+    game_2.assign_bits();
+    Ok(game_2)
+}
+
+fn calculate_max_profit_2(
+    game_info: &TunnelMap,
+    visiting_index: Option<i32>,
+    visited: &Bitmask,
+    memo: &mut BTreeMap<Bitmask, i32>,
+    time_left: i32,
+    total_profit: i32,
+) -> i32 {
+    update_memo(memo, *visited, total_profit);
+
+    let mut max_profit = 0;
+
+    let to_visit_list = match visiting_index {
+        Some(index) => game_info.get_neighbour(index),
+        None => game_info.get_index_list(),
+    };
+
+    for to_visit in to_visit_list {
+        let Some(bit) = game_info.get_bit(to_visit) else {
+            continue;
+        };
+        if visited.get(bit) {
+            continue;
+        }
+
+        let mut updated_visited = *visited;
+
+        let time_on_travel = game_info.get_time_on_travel(visiting_index, to_visit);
+        let to_visit_profit = game_info.get_profit(to_visit);
+
+        let updated_time_left = time_left - time_on_travel - 1;
+        updated_visited.set(bit);
+
+        let current_profit = get_profits(time_left, time_on_travel, to_visit_profit);
+        if current_profit <= 0 || time_left - time_on_travel - 1 <= 0 {
+            continue;
+        }
+        let after_profit = calculate_max_profit_2(
+            game_info,
+            Some(to_visit as i32),
+            &updated_visited,
+            memo,
+            updated_time_left,
+            total_profit + current_profit,
+        );
+        let temp_profit = current_profit + after_profit;
+        if temp_profit > max_profit {
+            max_profit = temp_profit;
+        }
+    }
+
+    max_profit
+}
+
+// slow but obviously right version of the search, trying every move minute
+// by minute along the tunnels themselves. it remembers every state it has
+// seen, which only stays small enough for tests on a handful of valves
+pub fn naive_max_pressure(game: &TunnelMap, time: i32, with_elephant: bool) -> i32 {
+    let start = game.find_valve_index("AA") as usize;
+    let elephant = if with_elephant { Some(start) } else { None };
+    let mut memo = HashMap::new();
+    naive_search(game, (start, elephant), 0, time, &mut memo)
+}
+
+// valve you stand at and, when it helps out, the one the elephant stands at
+type Walkers = (usize, Option<usize>);
+
+// moves of one walker: open the valve it stands at or walk down a tunnel
+fn naive_moves(game: &TunnelMap, valve: usize, opened: u64) -> Vec<(usize, u64)> {
+    let mut moves = Vec::new();
+    if let Some(bit) = game.get_bit(valve) {
+        if opened & 1 << bit == 0 {
+            moves.push((valve, opened | 1 << bit));
+        }
+    }
+    for (next, &dist) in game.tunnel_map[valve].iter().enumerate() {
+        if dist == 1 {
+            moves.push((next, opened));
+        }
+    }
+    moves
+}
+
+fn naive_search(
+    game: &TunnelMap,
+    at: Walkers,
+    opened: u64,
+    time_left: i32,
+    memo: &mut HashMap<(Walkers, u64, i32), i32>,
+) -> i32 {
+    if time_left == 0 {
+        return 0;
+    }
+    if let Some(&best) = memo.get(&(at, opened, time_left)) {
+        return best;
+    }
+
+    // pressure released this minute by every valve open so far
+    let released = (0..game.tunnel_map.len())
+        .filter(|&valve| {
+            game.get_bit(valve)
+                .is_some_and(|bit| opened & 1 << bit != 0)
+        })
+        .map(|valve| game.get_profit(valve))
+        .sum::<i32>();
+    let mut best = 0;
+    for (mine, opened) in naive_moves(game, at.0, opened) {
+        match at.1 {
+            Some(elephant) => {
+                for (theirs, opened) in naive_moves(game, elephant, opened) {
+                    let after =
+                        naive_search(game, (mine, Some(theirs)), opened, time_left - 1, memo);
+                    best = max(best, after);
+                }
+            }
+            None => {
+                let after = naive_search(game, (mine, None), opened, time_left - 1, memo);
+                best = max(best, after);
+            }
+        }
+    }
+    let best = released + best;
+    memo.insert((at, opened, time_left), best);
+    best
+}
+
+fn update_memo(memo: &mut BTreeMap<Bitmask, i32>, visited: Bitmask, profit: i32) {
+    let new_key = visited;
+    let current_value = memo.get(&new_key).unwrap_or(&0);
+    memo.insert(new_key, max(*current_value, profit));
+}
+
+fn get_profits(n: i32, time_on_travel: i32, profit: i32) -> i32 {
+    (n - time_on_travel - 1) * profit
+}
+
+// size valves, starting from AA, strung along winding tunnels with a few
+// shortcuts. like in a real input only a quarter of them, and at most
+// fifteen, have a flow rate, which keeps both parts quick
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+    let mut names = Vec::new();
+    for first in 'A'..='Z' {
+        for second in 'A'..='Z' {
+            names.push(format!("{}{}", first, second));
+        }
+    }
+    names.retain(|name| name != "AA");
+    rng.shuffle(&mut names);
+    names.insert(0, "AA".to_string());
+    names.truncate(count);
+
+    let mut tunnels = vec![BTreeSet::new(); count];
+    let mut link = |from: usize, to: usize| {
+        tunnels[from].insert(to);
+        tunnels[to].insert(from);
+    };
+    for valve in 1..count {
+        link(valve, valve - 1 - rng.index(valve.min(3)));
+    }
+    for _ in 0..count / 8 {
+        let (from, to) = (rng.index(count), rng.index(count));
+        if from != to {
+            link(from, to);
+        }
+    }
+
+    let mut flow_rates = vec![0; count];
+    let mut with_flow = (1..count).collect::<Vec<_>>();
+    rng.shuffle(&mut with_flow);
+    for &valve in with_flow.iter().take((count / 4).clamp(1, 15)) {
+        flow_rates[valve] = rng.range(1, 25);
+    }
+
+    let mut lines = Vec::new();
+    for valve in 0..count {
+        let neighbours = tunnels[valve]
+            .iter()
+            .map(|&neighbour| names[neighbour].as_str())
+            .collect::<Vec<_>>();
+        let tunnel = match neighbours.len() {
+            1 => "tunnel leads to valve",
+            _ => "tunnels lead to valves",
+        };
+        lines.push(format!(
+            "Valve {} has flow rate={}; {} {}\n",
+            names[valve],
+            flow_rates[valve],
+            tunnel,
+            neighbours.join(", ")
+        ));
+    }
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
@@ -0,0 +1,450 @@
+/*
+input:
+jetstream pattern in < and >, repeat if finished
+
+output:
+height after 2022 rock
+
+5 rock pattern
+
+chamber
+ - width 7 units
+
+rock spawning
+ - 2 units from the left edge
+ - 3 units above the highest rock
+
+mechanics
+ - push by jetstream
+ - falling one unit down
+    - if rock stop (no more movement) -> spawn new rock
+ */
+
+/*
+problems:
+ - game mechanics cycle
+    - spawn rock
+    - move rock (include getting pushed by jetstream and stopping)
+ - how to detect collision
+    - ?
+ */
+
+use std::{
+    cmp::max,
+    collections::{HashMap, HashSet},
+};
+
+use aoc_common::{Frame, FrameSink, ParseError, Rng};
+use serde::{Deserialize, Serialize};
+
+// rows below the top of the tower looked at for repeating surfaces
+const SURFACE_DEPTH: i64 = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rock {
+    coords: Vec<Vec<(i64, i64)>>,
+    index: usize,
+}
+
+impl Rock {
+    fn new() -> Rock {
+        let vec_to_add = vec![
+            vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+            vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            vec![(0, 0), (0, 1), (0, 2), (0, 3)],
+            vec![(0, 0), (1, 0), (0, 1), (1, 1)],
+        ];
+
+        Rock {
+            coords: vec_to_add,
+            index: 0,
+        }
+    }
+
+    fn next(&mut self) -> Vec<(i64, i64)> {
+        let result = self.coords[self.index].clone();
+        self.index = (self.index + 1) % self.coords.len();
+        result
+    }
+
+    fn get_index(&self) -> i64 {
+        self.index as i64
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Jetstream {
+    positions: Vec<i64>,
+    index: usize,
+}
+
+impl Jetstream {
+    pub fn new(input: &str) -> Result<Jetstream, ParseError> {
+        let pattern = input.trim();
+        let mut positions = Vec::new();
+        for (index, c) in pattern.char_indices() {
+            match c {
+                '<' => positions.push(-1),
+                '>' => positions.push(1),
+                _ => {
+                    let token = &pattern[index..index + c.len_utf8()];
+                    return Err(ParseError::at(input, token, "< or >"));
+                }
+            }
+        }
+        if positions.is_empty() {
+            return Err(ParseError::at(input, pattern, "a jet pattern"));
+        }
+        Ok(Jetstream {
+            positions,
+            index: 0,
+        })
+    }
+
+    fn next(&mut self) -> i64 {
+        let result = self.positions[self.index];
+        self.index = (self.index + 1) % self.positions.len();
+        result
+    }
+
+    fn get_index(&self) -> i64 {
+        self.index as i64
+    }
+}
+
+pub struct Game {
+    highest_height: i64,      // starting height for spawing new rock
+    map: HashSet<(i64, i64)>, // map of all taken spot
+}
+
+impl Game {
+    pub fn new() -> Game {
+        Game {
+            highest_height: 0,
+            map: HashSet::new(),
+        }
+    }
+
+    fn spawn_rock(&self, rock: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+        let x = 2;
+        let y = self.highest_height + 3;
+        rock.iter()
+            .map(|coord| (coord.0 + x, coord.1 + y))
+            .collect()
+    }
+
+    fn move_rock_by_jetstream(
+        &self,
+        rock: &[(i64, i64)],
+        jetstream: &mut Jetstream,
+    ) -> Vec<(i64, i64)> {
+        let position_to_move = jetstream.next();
+        rock.iter()
+            .map(|coord| (coord.0 + position_to_move, coord.1))
+            .collect()
+    }
+
+    fn move_rock_by_gravity(&self, rock: &[(i64, i64)]) -> Vec<(i64, i64)> {
+        rock.iter().map(|coord| (coord.0, coord.1 - 1)).collect()
+    }
+
+    fn is_out_of_bound(&self, rock: &[(i64, i64)], height: i64) -> bool {
+        for coord in rock {
+            let x = coord.0;
+            let y = coord.1;
+            if !(0..=6).contains(&x) || y < height {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn is_collided(&self, rock: &[(i64, i64)]) -> bool {
+        for coord in rock {
+            let x = coord.0;
+            let y = coord.1;
+            if self.map.contains(&(x, y)) {
+                return true;
+            }
+        }
+        false
+    }
+
+    // empty spots a falling rock could still reach within the top rows,
+    // relative to the top of the tower. only the top rows would not do, a
+    // rock can slide into a gap further down. cells on the lowest row looked
+    // at are columns left open, which could go on all the way down, so the
+    // search stops there and two towers with the same surface only play out
+    // the same while no rock falls past it
+    fn get_surface(&self) -> Vec<(i64, i64)> {
+        let bottom = max(0, self.highest_height - SURFACE_DEPTH);
+        let mut reached = HashSet::new();
+        let mut queue = (0..7)
+            .map(|x| (x, self.highest_height))
+            .collect::<Vec<(i64, i64)>>();
+        while let Some((x, y)) = queue.pop() {
+            if !(0..=6).contains(&x) || y < bottom || self.map.contains(&(x, y)) {
+                continue;
+            }
+            if !reached.insert((x, y)) {
+                continue;
+            }
+            queue.extend([(x - 1, y), (x + 1, y), (x, y - 1)]);
+        }
+
+        let mut surface = reached
+            .into_iter()
+            .map(|(x, y)| (x, self.highest_height - y))
+            .collect::<Vec<(i64, i64)>>();
+        surface.sort();
+        surface
+    }
+
+    pub fn move_n_number_of_rocks(&mut self, jetstream: &mut Jetstream, n: i64) -> i64 {
+        let mut memo = HashMap::new();
+        let mut lazy = HashMap::<i64, i64>::new();
+        let min_height = 0;
+        let mut rock_factory = Rock::new();
+        let mut index = 1;
+        while index <= n {
+            let rock = rock_factory.next();
+            let mut starting_rock = self.spawn_rock(rock);
+            loop {
+                let rock_before_jetstream = starting_rock;
+                let rock_after_jetstream =
+                    self.move_rock_by_jetstream(&rock_before_jetstream, jetstream);
+                let rock_before_gravity = match self
+                    .is_out_of_bound(&rock_after_jetstream, min_height)
+                    || self.is_collided(&rock_after_jetstream)
+                {
+                    true => rock_before_jetstream,
+                    false => rock_after_jetstream,
+                };
+                let rock_after_gravity = self.move_rock_by_gravity(&rock_before_gravity);
+                if self.is_collided(&rock_after_gravity)
+                    || self.is_out_of_bound(&rock_after_gravity, min_height)
+                {
+                    starting_rock = rock_before_gravity;
+                    break;
+                } else {
+                    starting_rock = rock_after_gravity;
+                }
+            }
+            // a rock that went below the surface looked at rows two towers
+            // with the same surface could differ in, so no cycle can run
+            // through it
+            let lowest = starting_rock.iter().map(|coord| coord.1).min().unwrap();
+            if lowest <= self.highest_height - SURFACE_DEPTH {
+                memo.clear();
+            }
+            self.map
+                .extend(starting_rock.iter().map(|coord| (coord.0, coord.1)));
+            self.highest_height = max(
+                self.highest_height,
+                starting_rock.iter().map(|coord| coord.1 + 1).max().unwrap(),
+            );
+
+            let jetstream_index = jetstream.get_index();
+            let rock_index = rock_factory.get_index();
+            let key = (rock_index, jetstream_index, self.get_surface());
+            if let Some((before_height, before_index)) = memo.get(&key) {
+                let delta_height = self.highest_height - before_height;
+                let delta_index = index - before_index;
+
+                let cycle_to_bypass = (n - before_index) / delta_index;
+                let remainder = (n - before_index) % delta_index;
+                let height_on_cycle = cycle_to_bypass * delta_height + before_height;
+                let height_after_cycle =
+                    lazy.get(&(before_index + remainder)).unwrap() - before_height;
+
+                return height_on_cycle + height_after_cycle;
+            } else {
+                lazy.insert(index, self.highest_height);
+                memo.insert(key, (self.highest_height, index));
+            }
+            index += 1;
+        }
+
+        self.highest_height
+    }
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// jet pattern, repeated once it runs out
+pub fn parse(input: &str) -> Result<Jetstream, ParseError> {
+    Jetstream::new(input)
+}
+
+// rocks dropped in each part
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part1_rocks: i64,
+    pub part2_rocks: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_rocks: 2022,
+            part2_rocks: 1000000000000,
+        }
+    }
+}
+
+pub fn solve_part1(jetstream: &Jetstream) -> i64 {
+    solve_part1_with(jetstream, &Params::default())
+}
+
+pub fn solve_part2(jetstream: &Jetstream) -> i64 {
+    solve_part2_with(jetstream, &Params::default())
+}
+
+pub fn solve_part1_with(jetstream: &Jetstream, params: &Params) -> i64 {
+    solution(jetstream, params.part1_rocks)
+}
+
+pub fn solve_part2_with(jetstream: &Jetstream, params: &Params) -> i64 {
+    solution(jetstream, params.part2_rocks)
+}
+
+// height of the tower after n rocks have fallen
+pub fn solution(jetstream: &Jetstream, n: i64) -> i64 {
+    let mut jetstream = jetstream.clone();
+    let mut game = Game::new();
+    game.move_n_number_of_rocks(&mut jetstream, n)
+}
+
+// slow but obviously right version of solution that drops every one of the
+// n rocks, to test the cycle detection on small inputs
+pub fn naive_solution(jetstream: &Jetstream, n: i64) -> i64 {
+    drop_rocks(jetstream, n, |_, _, _, _| {}).len() as i64
+}
+
+// rows of the tower drawn below its top while rocks fall, nobody looks
+// further down
+const REPLAY_DEPTH: i64 = 40;
+
+// rows count up from the floor while frames count down
+fn at(x: i64, y: i64) -> (i32, i32) {
+    (x as i32, -(y as i32))
+}
+
+// the walls and the settled rocks of the rows from bottom to top, with the
+// floor once it is among them
+fn draw_chamber(frame: &mut Frame, chamber: &[u8], bottom: i64, top: i64) {
+    for y in bottom..top {
+        frame.set(at(-1, y), '|');
+        frame.set(at(7, y), '|');
+        for x in 0..7 {
+            if chamber.get(y as usize).is_some_and(|row| row & 1 << x != 0) {
+                frame.set(at(x, y), '#');
+            }
+        }
+    }
+    if bottom == 0 {
+        for x in -1..=7 {
+            frame.set(at(x, -1), if x == -1 || x == 7 { '+' } else { '-' });
+        }
+    }
+}
+
+// one frame per move of a falling rock over the 2022 rocks of part one,
+// the trillion of part two would never end. those only show the top of the
+// tower, the last frame is all of it
+pub fn replay(jetstream: &Jetstream, _part: u8, sink: &mut dyn FrameSink) {
+    let chamber = drop_rocks(jetstream, 2022, |chamber, shape, (x, y), number| {
+        if !sink.wants_frame() {
+            return;
+        }
+        let rock = shape
+            .iter()
+            .map(|(dx, dy)| (x + dx, y + dy))
+            .collect::<Vec<_>>();
+        let mut frame = Frame::new();
+        let top = rock.iter().map(|(_, y)| y + 1).max().unwrap_or(0);
+        let top = top.max(chamber.len() as i64);
+        draw_chamber(&mut frame, chamber, (top - REPLAY_DEPTH).max(0), top);
+        for &(x, y) in &rock {
+            frame.set(at(x, y), '@');
+        }
+        frame.focus_on(at(3, rock[0].1));
+        frame.set_caption(format!("rock {}, tower height {}", number, chamber.len()));
+        sink.frame(&frame);
+    });
+
+    if sink.final_only() || sink.wants_frame() {
+        let mut frame = Frame::new();
+        let top = chamber.len() as i64;
+        draw_chamber(&mut frame, &chamber, 0, top);
+        frame.focus_on(at(3, top));
+        frame.set_caption(format!("2022 rocks, tower height {}", top));
+        sink.frame(&frame);
+    }
+}
+
+// drops n rocks into a chamber kept as one row of seven bits per unit of
+// height, calling step with the shape of the falling rock and where it is
+// after each of its moves. returns the chamber, as high as the tower
+fn drop_rocks(
+    jetstream: &Jetstream,
+    n: i64,
+    mut step: impl FnMut(&[u8], &[(i64, i64)], (i64, i64), i64),
+) -> Vec<u8> {
+    let shapes = Rock::new().coords;
+    let mut jets = jetstream.positions.iter().cycle();
+    let mut chamber = Vec::<u8>::new();
+    let fits = |chamber: &[u8], rock: &[(i64, i64)], x: i64, y: i64| {
+        rock.iter().all(|(dx, dy)| {
+            let (x, y) = (x + dx, y + dy);
+            (0..7).contains(&x)
+                && y >= 0
+                && chamber.get(y as usize).is_none_or(|row| row & 1 << x == 0)
+        })
+    };
+
+    for (number, rock) in (1..).zip(shapes.iter().cycle().take(n as usize)) {
+        let (mut x, mut y) = (2, chamber.len() as i64 + 3);
+        loop {
+            step(&chamber, rock, (x, y), number);
+            let pushed = x + jets.next().unwrap();
+            if fits(&chamber, rock, pushed, y) {
+                x = pushed;
+            }
+            if !fits(&chamber, rock, x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+        for (dx, dy) in rock {
+            let row = (y + dy) as usize;
+            if chamber.len() <= row {
+                chamber.resize(row + 1, 0);
+            }
+            chamber[row] |= 1 << (x + dx);
+        }
+    }
+    chamber
+}
+
+// size jets
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let jets = (0..size.max(1))
+        .map(|_| if rng.one_in(2) { '<' } else { '>' })
+        .collect::<String>();
+    jets + "\n"
+}
@@ -0,0 +1,321 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::{next_token, parse_token, Frame, FrameSink, ParseError, Rng};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Cube {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl Cube {
+    fn get_neighbours(&self) -> Vec<Cube> {
+        let mut neighbours = Vec::new();
+
+        for offset in [-1, 1].iter() {
+            neighbours.push(Cube {
+                x: self.x + offset,
+                y: self.y,
+                z: self.z,
+            });
+            neighbours.push(Cube {
+                x: self.x,
+                y: self.y + offset,
+                z: self.z,
+            });
+            neighbours.push(Cube {
+                x: self.x,
+                y: self.y,
+                z: self.z + offset,
+            });
+        }
+
+        neighbours.to_vec()
+    }
+}
+
+struct Game {
+    cube_set: HashSet<Cube>,
+    visited: HashSet<Cube>,
+}
+
+impl Game {
+    fn new(cube_set: HashSet<Cube>) -> Game {
+        Game {
+            cube_set,
+            visited: HashSet::new(),
+        }
+    }
+
+    fn get_all_cubes(&self) -> Vec<Cube> {
+        self.cube_set.iter().copied().collect()
+    }
+
+    fn generate_surface_area(&mut self, cube: Cube, wanted_neighbours: &HashSet<Cube>) -> i32 {
+        if self.visited.contains(&cube) {
+            return 0;
+        }
+        self.visited.insert(cube);
+        let good_neighbours = self.get_neighbours(&cube, true, wanted_neighbours);
+        let mut total_surface_area = 6 - good_neighbours.len() as i32;
+        for nxt_cube in good_neighbours {
+            total_surface_area += self.generate_surface_area(nxt_cube, wanted_neighbours);
+        }
+        total_surface_area
+    }
+
+    fn get_neighbours(
+        &self,
+        cube: &Cube,
+        is_inside: bool,
+        wanted_neighbours: &HashSet<Cube>,
+    ) -> Vec<Cube> {
+        let mut neighbours = Vec::new();
+
+        for offset in [-1, 1].iter() {
+            neighbours.push(Cube {
+                x: cube.x + offset,
+                y: cube.y,
+                z: cube.z,
+            });
+            neighbours.push(Cube {
+                x: cube.x,
+                y: cube.y + offset,
+                z: cube.z,
+            });
+            neighbours.push(Cube {
+                x: cube.x,
+                y: cube.y,
+                z: cube.z + offset,
+            });
+        }
+
+        if is_inside {
+            neighbours
+                .iter()
+                .filter(|cube| wanted_neighbours.contains(cube))
+                .copied()
+                .collect()
+        } else {
+            neighbours
+                .iter()
+                .filter(|cube| !wanted_neighbours.contains(cube))
+                .copied()
+                .collect()
+        }
+    }
+}
+
+struct Game2 {
+    cube_set: HashSet<Cube>,
+    min_x: i32,
+    min_y: i32,
+    min_z: i32,
+    max_x: i32,
+    max_y: i32,
+    max_z: i32,
+}
+
+impl Game2 {
+    fn new(cube_set: HashSet<Cube>) -> Game2 {
+        // get min in cube set
+        let min_x = cube_set.iter().min_by_key(|cube| cube.x).unwrap().x - 1;
+        let min_y = cube_set.iter().min_by_key(|cube| cube.y).unwrap().y - 1;
+        let min_z = cube_set.iter().min_by_key(|cube| cube.z).unwrap().z - 1;
+
+        let mx_x = cube_set.iter().max_by_key(|cube| cube.x).unwrap().x + 1;
+        let mx_y = cube_set.iter().max_by_key(|cube| cube.y).unwrap().y + 1;
+        let mx_z = cube_set.iter().max_by_key(|cube| cube.z).unwrap().z + 1;
+
+        Game2 {
+            cube_set,
+            min_x,
+            min_y,
+            min_z,
+            max_x: mx_x,
+            max_y: mx_y,
+            max_z: mx_z,
+        }
+    }
+
+    // faces of the droplet reached from outside, and the air around it
+    fn traverse(&self) -> (i32, HashSet<Cube>) {
+        let good_neighbour_set = self.cube_set.iter().cloned().collect::<HashSet<Cube>>();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        let mut count = 0;
+        let starting = Cube {
+            x: self.min_x,
+            y: self.min_y,
+            z: self.min_z,
+        };
+        queue.push_back(starting);
+
+        while let Some(current) = queue.pop_front() {
+            if visited.contains(&current) {
+                continue;
+            }
+            visited.insert(current);
+            let neighbours_to_visit = current.get_neighbours();
+
+            count += neighbours_to_visit
+                .iter()
+                .filter(|cube| good_neighbour_set.contains(cube))
+                .count() as i32;
+
+            for neighbour in neighbours_to_visit.iter().filter(|cube| {
+                !self.is_out_of_bound(current.x, current.y, current.z)
+                    && !good_neighbour_set.contains(cube)
+            }) {
+                queue.push_back(*neighbour);
+            }
+        }
+
+        (count, visited)
+    }
+
+    fn is_out_of_bound(&self, x: i32, y: i32, z: i32) -> bool {
+        x < self.min_x
+            || x > self.max_x
+            || y < self.min_y
+            || y > self.max_y
+            || z < self.min_z
+            || z > self.max_z
+    }
+}
+
+pub fn read_input(input: &str) -> Result<HashSet<Cube>, ParseError> {
+    let mut cube_set = HashSet::new();
+
+    for line in input.lines() {
+        // split by , convert to i32
+        let mut tokens = line.split(',');
+        let mut coords = [0; 3];
+        for coord in coords.iter_mut() {
+            let token = next_token(input, line, &mut tokens, "a coordinate")?;
+            *coord = parse_token::<i32>(input, token, "a coordinate")?;
+        }
+        if let Some(token) = tokens.next() {
+            return Err(ParseError::at(input, token, "end of line"));
+        }
+        let cube = Cube {
+            x: coords[0],
+            y: coords[1],
+            z: coords[2],
+        };
+        cube_set.insert(cube);
+    }
+
+    if cube_set.is_empty() {
+        return Err(ParseError::new(1, 1, "a cube"));
+    }
+    Ok(cube_set)
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// lava droplet as a set of unit cubes
+pub fn parse(input: &str) -> Result<HashSet<Cube>, ParseError> {
+    read_input(input)
+}
+
+// surface area including trapped air pockets
+pub fn solve_part1(cube_set: &HashSet<Cube>) -> i32 {
+    let mut game = Game::new(cube_set.clone());
+    let cubes = game.get_all_cubes();
+    let good_neighbour_set = cubes.iter().cloned().collect::<HashSet<Cube>>();
+    let mut total_surface_area = 0;
+    for cube in cubes.clone() {
+        total_surface_area += game.generate_surface_area(cube, &good_neighbour_set);
+    }
+    total_surface_area
+}
+
+// exterior surface area only
+pub fn solve_part2(cube_set: &HashSet<Cube>) -> i32 {
+    let game2 = Game2::new(cube_set.clone());
+    game2.traverse().0
+}
+
+// one frame per slice of the droplet from bottom to top, lava as #. part
+// two marks the pockets of air trapped inside as ~
+pub fn replay(cube_set: &HashSet<Cube>, part: u8, sink: &mut dyn FrameSink) {
+    let game2 = Game2::new(cube_set.clone());
+    let outside = match part {
+        1 => HashSet::new(),
+        _ => game2.traverse().1,
+    };
+    // the slice at z with its corner moved by offset
+    let draw = |frame: &mut Frame, z, (dx, dy)| {
+        for y in game2.min_y + 1..game2.max_y {
+            for x in game2.min_x + 1..game2.max_x {
+                let cube = Cube { x, y, z };
+                let c = if cube_set.contains(&cube) {
+                    '#'
+                } else if part != 1 && !outside.contains(&cube) {
+                    '~'
+                } else {
+                    '.'
+                };
+                frame.set((x + dx, y + dy), c);
+            }
+        }
+    };
+    let slices = game2.min_z + 1..game2.max_z;
+    for z in slices.clone() {
+        if !sink.wants_frame() {
+            continue;
+        }
+        let mut frame = Frame::new();
+        draw(&mut frame, z, (0, 0));
+        frame.set_caption(format!("z = {}", z));
+        sink.frame(&frame);
+    }
+
+    // then every slice at once, side by side in rows with a blank line and
+    // column between them, for a picture of the whole droplet
+    if !(sink.final_only() || sink.wants_frame()) || slices.is_empty() {
+        return;
+    }
+    let count = slices.len();
+    let columns = (count as f64).sqrt().ceil() as usize;
+    let width = game2.max_x - game2.min_x;
+    let height = game2.max_y - game2.min_y;
+    let mut frame = Frame::new();
+    for (index, z) in slices.clone().enumerate() {
+        let offset = (
+            (index % columns) as i32 * width,
+            (index / columns) as i32 * height,
+        );
+        draw(&mut frame, z, offset);
+    }
+    frame.set_caption(format!("z = {} to {}", slices.start, slices.end - 1));
+    sink.frame(&frame);
+}
+
+// size distinct cubes packed into a box about twice their cube root wide,
+// dense enough to enclose pockets of air
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = ((size as f64).cbrt() * 2.0).ceil() as i64 + 1;
+    let count = size.clamp(1, (side * side * side) as usize);
+    let mut cube_set = HashSet::new();
+    let mut output = String::new();
+    while cube_set.len() < count {
+        let cube = (
+            rng.range(0, side - 1),
+            rng.range(0, side - 1),
+            rng.range(0, side - 1),
+        );
+        if cube_set.insert(cube) {
+            output += &format!("{},{},{}\n", cube.0, cube.1, cube.2);
+        }
+    }
+    output
+}
@@ -0,0 +1,590 @@
+use std::hash::{Hash, Hasher};
+use std::{any::Any, cmp::max, collections::HashSet};
+
+use aoc_common::{parse_token, ParseError, Rng};
+use rayon::prelude::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+// static index for blueprint
+const ORE_ROBOT: usize = 0;
+const CLAY_ROBOT: usize = 1;
+const OBSIDIAN_ROBOT: usize = 2;
+const GEODE_ROBOT: usize = 3;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BlueprintList {
+    ore_robot_blueprint: OreRobotBlueprint,
+    clay_robot_blueprint: ClayRobotBlueprint,
+    obsidian_robot_blueprint: ObsidianRobotBlueprint,
+    geode_robot_blueprint: GeodeRobotBlueprint,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct OreRobotBlueprint {
+    ore: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ClayRobotBlueprint {
+    ore: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ObsidianRobotBlueprint {
+    ore: u32,
+    clay: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct GeodeRobotBlueprint {
+    ore: u32,
+    obsidian: u32,
+}
+
+impl PartialEq for dyn Blueprint {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_id() == other.get_id()
+    }
+}
+
+impl Eq for dyn Blueprint {}
+
+impl Hash for dyn Blueprint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get_id().hash(state);
+    }
+}
+
+trait Blueprint: Any {
+    fn build(&self, state: StateMachine) -> StateMachine;
+
+    fn is_buildable_from(&self, state: StateMachine) -> bool;
+
+    fn get_ore_requirement(&self) -> u32;
+
+    fn get_clay_requirement(&self) -> u32;
+
+    fn get_obsidian_requirement(&self) -> u32;
+
+    fn get_geode_requirement(&self) -> u32;
+
+    fn get_id(&self) -> usize;
+}
+
+impl Blueprint for OreRobotBlueprint {
+    fn build(&self, state: StateMachine) -> StateMachine {
+        let mut new_state = state;
+
+        new_state.ore_robot_number += 1;
+        new_state.ore -= self.ore;
+        new_state
+    }
+
+    fn is_buildable_from(&self, state: StateMachine) -> bool {
+        state.ore >= self.ore
+    }
+
+    fn get_ore_requirement(&self) -> u32 {
+        self.ore
+    }
+
+    fn get_clay_requirement(&self) -> u32 {
+        0
+    }
+
+    fn get_obsidian_requirement(&self) -> u32 {
+        0
+    }
+
+    fn get_geode_requirement(&self) -> u32 {
+        0
+    }
+
+    fn get_id(&self) -> usize {
+        ORE_ROBOT
+    }
+}
+
+impl Blueprint for ClayRobotBlueprint {
+    fn build(&self, state: StateMachine) -> StateMachine {
+        let mut new_state = state;
+
+        new_state.clay_robot_number += 1;
+        new_state.ore -= self.ore;
+        new_state
+    }
+
+    fn is_buildable_from(&self, state: StateMachine) -> bool {
+        state.ore >= self.ore
+    }
+
+    fn get_ore_requirement(&self) -> u32 {
+        self.ore
+    }
+
+    fn get_clay_requirement(&self) -> u32 {
+        0
+    }
+
+    fn get_obsidian_requirement(&self) -> u32 {
+        0
+    }
+
+    fn get_geode_requirement(&self) -> u32 {
+        0
+    }
+
+    fn get_id(&self) -> usize {
+        CLAY_ROBOT
+    }
+}
+
+impl Blueprint for ObsidianRobotBlueprint {
+    fn build(&self, state: StateMachine) -> StateMachine {
+        let mut new_state = state;
+
+        new_state.obsidian_robot_number += 1;
+        new_state.clay -= self.clay;
+        new_state.ore -= self.ore;
+        new_state
+    }
+
+    fn is_buildable_from(&self, state: StateMachine) -> bool {
+        state.clay >= self.clay && state.ore >= self.ore
+    }
+
+    fn get_ore_requirement(&self) -> u32 {
+        self.ore
+    }
+
+    fn get_clay_requirement(&self) -> u32 {
+        self.clay
+    }
+
+    fn get_obsidian_requirement(&self) -> u32 {
+        0
+    }
+
+    fn get_geode_requirement(&self) -> u32 {
+        0
+    }
+
+    fn get_id(&self) -> usize {
+        OBSIDIAN_ROBOT
+    }
+}
+
+impl Blueprint for GeodeRobotBlueprint {
+    fn build(&self, state: StateMachine) -> StateMachine {
+        let mut new_state = state;
+
+        new_state.geode_robot_number += 1;
+        new_state.obsidian -= self.obsidian;
+        new_state.ore -= self.ore;
+        new_state
+    }
+
+    fn is_buildable_from(&self, state: StateMachine) -> bool {
+        state.obsidian >= self.obsidian && state.ore >= self.ore
+    }
+
+    fn get_ore_requirement(&self) -> u32 {
+        self.ore
+    }
+
+    fn get_clay_requirement(&self) -> u32 {
+        0
+    }
+
+    fn get_obsidian_requirement(&self) -> u32 {
+        self.obsidian
+    }
+
+    fn get_geode_requirement(&self) -> u32 {
+        0
+    }
+
+    fn get_id(&self) -> usize {
+        GEODE_ROBOT
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct StateMachine {
+    id: u32,
+    ore_robot_number: u32,
+    clay_robot_number: u32,
+    obsidian_robot_number: u32,
+    geode_robot_number: u32,
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+    geode: u32,
+    prev: Option<usize>, // index of blueprint 0: ore, 1: clay, 2: obsidian, 3: geode
+    time_left: u32,
+}
+
+impl StateMachine {
+    pub fn new(id: u32, time_left: u32) -> StateMachine {
+        StateMachine {
+            id,
+            ore_robot_number: 1,
+            clay_robot_number: 0,
+            obsidian_robot_number: 0,
+            geode_robot_number: 0,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+            prev: None,
+            time_left,
+        }
+    }
+
+    fn generate_ore(&self) -> (u32, u32, u32, u32) {
+        (
+            self.ore_robot_number,
+            self.clay_robot_number,
+            self.obsidian_robot_number,
+            self.geode_robot_number,
+        )
+    }
+
+    //
+    fn get_transitional_states(&self, blueprints: &BlueprintList) -> Vec<StateMachine> {
+        let mut transitional_state = Vec::new();
+
+        // only always build geode
+        let recommendations = get_recommendations(self, blueprints);
+        for blueprint in recommendations {
+            let mut buildable_state = *self;
+            // doing the lazy way of getting the transitional state
+            while !blueprint.is_buildable_from(buildable_state) && buildable_state.time_left > 1 {
+                buildable_state = buildable_state.rest();
+            }
+
+            if blueprint.is_buildable_from(buildable_state) && self.time_left > 0 {
+                transitional_state.push(buildable_state.get_transitional_state(blueprint));
+            }
+        }
+
+        transitional_state
+    }
+
+    fn rest(&self) -> StateMachine {
+        let mut new_state = *self;
+        new_state.time_left -= 1;
+        let ore_gains = self.generate_ore();
+        new_state.ore += ore_gains.0;
+        new_state.clay += ore_gains.1;
+        new_state.obsidian += ore_gains.2;
+        new_state.geode += ore_gains.3;
+
+        new_state
+    }
+
+    fn get_transitional_state(&self, blueprint: &dyn Blueprint) -> StateMachine {
+        let ore_gains = self.generate_ore();
+        let mut new_state = blueprint.build(*self);
+        new_state.time_left -= 1;
+        new_state.ore += ore_gains.0;
+        new_state.clay += ore_gains.1;
+        new_state.obsidian += ore_gains.2;
+        new_state.geode += ore_gains.3;
+        new_state
+    }
+}
+
+pub fn game_1(state: StateMachine, blueprints: &BlueprintList) -> u32 {
+    let mut queue: Vec<_> = Vec::new();
+    let mut visited = HashSet::new();
+    queue.push(state);
+
+    let mut mx_geode = 0;
+    let mut space_taken = 0;
+    while let Some(current) = queue.pop() {
+        space_taken += 1;
+
+        // the geode robots keep opening geodes until the time runs out, even
+        // when nothing else gets built
+        let geode_at_end = current.geode + current.geode_robot_number * current.time_left;
+        mx_geode = max(mx_geode, geode_at_end);
+        for possible_state in current.get_transitional_states(blueprints) {
+            if visited.contains(&possible_state) || !can_beat_geode_record(possible_state, mx_geode)
+            {
+                continue;
+            }
+            queue.push(possible_state);
+            visited.insert(current);
+        }
+    }
+
+    log::debug!("space taken: {}", space_taken);
+    mx_geode
+}
+
+// slow but obviously right version of game_1 that tries every choice in
+// every minute, waiting or building any robot it can afford, and only drops
+// states it has already reached. it is meant for tests with short times
+pub fn naive_max_geodes(blueprints: &BlueprintList, time: u32) -> u32 {
+    // costs in ore, clay and obsidian of the ore, clay, obsidian and geode
+    // robots, the same order the robots and resources are counted in
+    let costs = [
+        [blueprints.ore_robot_blueprint.ore, 0, 0],
+        [blueprints.clay_robot_blueprint.ore, 0, 0],
+        [
+            blueprints.obsidian_robot_blueprint.ore,
+            blueprints.obsidian_robot_blueprint.clay,
+            0,
+        ],
+        [
+            blueprints.geode_robot_blueprint.ore,
+            0,
+            blueprints.geode_robot_blueprint.obsidian,
+        ],
+    ];
+
+    let mut states = HashSet::new();
+    states.insert(([1, 0, 0, 0], [0; 4]));
+    for _ in 0..time {
+        let mut next_states = HashSet::new();
+        for (robots, resources) in states {
+            let mut collected = resources;
+            for (resource, robot) in collected.iter_mut().zip(robots) {
+                *resource += robot;
+            }
+            next_states.insert((robots, collected));
+
+            for (robot, cost) in costs.iter().enumerate() {
+                if (0..3).any(|resource| resources[resource] < cost[resource]) {
+                    continue;
+                }
+                let mut built = robots;
+                built[robot] += 1;
+                let mut left = collected;
+                for resource in 0..3 {
+                    left[resource] -= cost[resource];
+                }
+                next_states.insert((built, left));
+            }
+        }
+        states = next_states;
+    }
+
+    states
+        .iter()
+        .map(|(_, resources)| resources[3])
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn read_input(input: &str) -> Result<Vec<BlueprintList>, ParseError> {
+    // delimeted by newline
+    let input_list = input.lines().filter(|line| !line.is_empty());
+
+    let mut blueprint_list = Vec::new();
+    let re = Regex::new(r"\d+").unwrap();
+    for instruction in input_list {
+        let numbers = re
+            .find_iter(instruction)
+            .map(|m| parse_token::<u32>(input, m.as_str(), "a cost"))
+            .collect::<Result<Vec<u32>, ParseError>>()?;
+        // blueprint id followed by the six robot costs
+        if numbers.len() != 7 {
+            let end = &instruction[instruction.len()..];
+            return Err(ParseError::at(input, end, "a blueprint id and six costs"));
+        }
+
+        let ore_robot = OreRobotBlueprint { ore: numbers[1] };
+        let clay_robot = ClayRobotBlueprint { ore: numbers[2] };
+        let obsidian_robot = ObsidianRobotBlueprint {
+            ore: numbers[3],
+            clay: numbers[4],
+        };
+        let geode_robot = GeodeRobotBlueprint {
+            ore: numbers[5],
+            obsidian: numbers[6],
+        };
+        let blueprint = BlueprintList {
+            ore_robot_blueprint: ore_robot,
+            clay_robot_blueprint: clay_robot,
+            obsidian_robot_blueprint: obsidian_robot,
+            geode_robot_blueprint: geode_robot,
+        };
+        blueprint_list.push(blueprint);
+        log::trace!("blueprint: {:?}", numbers);
+    }
+    Ok(blueprint_list)
+}
+
+fn can_beat_geode_record(state: StateMachine, mx_geode: u32) -> bool {
+    let time_left = state.time_left;
+    let current_geode = state.geode;
+    let optimum_geode =
+        current_geode + summation(current_geode + time_left) - summation(current_geode);
+
+    optimum_geode > mx_geode
+}
+
+fn summation(n: u32) -> u32 {
+    n * (n + 1) / 2
+}
+
+// ore -> geode
+// clay -> obsidian -> geode
+
+/*
+basically, if robot have enough supplier robots. this means that other robots are no longer needed
+*/
+/*
+sleep optimisation
+- no need to sleep if you can build everything.
+-
+
+ */
+
+fn get_recommendations<'a>(
+    state: &'a StateMachine,
+    blueprints: &'a BlueprintList,
+) -> Vec<&'a dyn Blueprint> {
+    let mut recommendations: HashSet<&dyn Blueprint> = HashSet::new();
+    recommendations.insert(&blueprints.geode_robot_blueprint);
+
+    if have_more_robot_then_required(state, &blueprints.geode_robot_blueprint) {
+        return vec![&blueprints.geode_robot_blueprint];
+    } else {
+        if state.obsidian_robot_number < blueprints.geode_robot_blueprint.get_obsidian_requirement()
+        {
+            recommendations.insert(&blueprints.obsidian_robot_blueprint);
+            if !have_more_robot_then_required(state, &blueprints.obsidian_robot_blueprint) {
+                if state.clay_robot_number
+                    < blueprints.obsidian_robot_blueprint.get_clay_requirement()
+                {
+                    recommendations.insert(&blueprints.clay_robot_blueprint);
+                    if !have_more_robot_then_required(state, &blueprints.clay_robot_blueprint)
+                        && state.ore_robot_number
+                            < blueprints.clay_robot_blueprint.get_ore_requirement()
+                    {
+                        recommendations.insert(&blueprints.ore_robot_blueprint);
+                    }
+                }
+                if state.ore_robot_number
+                    < blueprints.obsidian_robot_blueprint.get_ore_requirement()
+                {
+                    recommendations.insert(&blueprints.ore_robot_blueprint);
+                }
+            }
+        }
+
+        if state.ore_robot_number < blueprints.geode_robot_blueprint.get_ore_requirement() {
+            recommendations.insert(&blueprints.ore_robot_blueprint);
+        }
+    }
+
+    recommendations.iter().copied().collect()
+}
+
+fn have_more_robot_then_required(state: &StateMachine, blueprint: &dyn Blueprint) -> bool {
+    state.ore_robot_number >= blueprint.get_ore_requirement()
+        && state.obsidian_robot_number >= blueprint.get_obsidian_requirement()
+        && state.clay_robot_number >= blueprint.get_clay_requirement()
+        && state.geode_robot_number >= blueprint.get_geode_requirement()
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// one robot blueprint list per line
+pub fn parse(input: &str) -> Result<Vec<BlueprintList>, ParseError> {
+    read_input(input)
+}
+
+// sum of quality levels over 24 minutes
+// minutes of each part, and how many blueprints survive the elephants
+// eating them in part two
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part1_minutes: u32,
+    pub part2_minutes: u32,
+    pub part2_blueprints: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_minutes: 24,
+            part2_minutes: 32,
+            part2_blueprints: 3,
+        }
+    }
+}
+
+pub fn solve_part1(blueprint_list: &[BlueprintList]) -> u32 {
+    solve_part1_with(blueprint_list, &Params::default())
+}
+
+pub fn solve_part2(blueprint_list: &[BlueprintList]) -> u32 {
+    solve_part2_with(blueprint_list, &Params::default())
+}
+
+pub fn solve_part1_with(blueprint_list: &[BlueprintList], params: &Params) -> u32 {
+    // get index along with the blueprint
+    let mut solution_1_ans = 0;
+    let mx_geodes = max_geodes(blueprint_list, params.part1_minutes);
+    for (index, state_mx_geode) in mx_geodes.into_iter().enumerate() {
+        solution_1_ans += state_mx_geode * (index + 1) as u32;
+        log::debug!("ans: {} quality: {}", solution_1_ans, state_mx_geode);
+    }
+    solution_1_ans
+}
+
+// product of the geodes opened by the first three blueprints over 32 minutes
+pub fn solve_part2_with(blueprint_list: &[BlueprintList], params: &Params) -> u32 {
+    let mut solution_2_ans = 1;
+    let count = params.part2_blueprints.min(blueprint_list.len());
+    for state_mx_geode in max_geodes(&blueprint_list[..count], params.part2_minutes) {
+        solution_2_ans *= state_mx_geode;
+        log::debug!("ans: {} max_geode: {}", solution_2_ans, state_mx_geode);
+    }
+    solution_2_ans
+}
+
+// most geodes every blueprint opens in time, searched for in parallel when
+// there is a thread pool and returned in the order of the blueprints
+fn max_geodes(blueprint_list: &[BlueprintList], time: u32) -> Vec<u32> {
+    aoc_common::parallel(|| {
+        blueprint_list
+            .par_iter()
+            .enumerate()
+            .map(|(index, blueprints)| {
+                game_1(StateMachine::new((index + 1) as u32, time), blueprints)
+            })
+            .collect()
+    })
+}
+
+// size blueprints, at least the three part two looks at, costing about as
+// much as the ones of a real input
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut output = String::new();
+    for id in 1..=size.max(3) {
+        output += &format!(
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+            id,
+            rng.range(2, 4),
+            rng.range(2, 4),
+            rng.range(2, 4),
+            rng.range(5, 20),
+            rng.range(2, 4),
+            rng.range(5, 20)
+        );
+    }
+    output
+}
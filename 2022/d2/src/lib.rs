@@ -0,0 +1,115 @@
+use aoc_common::{next_token, ParseError, Rng};
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// (opponent hand, second column) for every round
+pub fn parse(input: &str) -> Result<Vec<(char, char)>, ParseError> {
+    load_rounds(input)
+}
+
+pub fn solve_part1(rounds: &[(char, char)]) -> i32 {
+    let mut mx = 0;
+    for &(oppo_hand, my_hand) in rounds {
+        let my_score = convert_to_score(my_hand);
+        mx += my_score + get_result_score(convert_to_score(oppo_hand), my_score);
+    }
+    mx
+}
+
+pub fn solve_part2(rounds: &[(char, char)]) -> i32 {
+    let mut mx = 0;
+    for &(oppo_hand, expected) in rounds {
+        mx += get_expected_score(oppo_hand, expected);
+    }
+    mx
+}
+
+fn load_rounds(input: &str) -> Result<Vec<(char, char)>, ParseError> {
+    let mut rounds = Vec::new();
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        let mut hands = line.split(' ');
+        let oppo_hand = next_token(input, line, &mut hands, "A, B or C")?;
+        let second = next_token(input, line, &mut hands, "X, Y or Z")?;
+        rounds.push((
+            parse_hand(input, oppo_hand, "ABC", "A, B or C")?,
+            parse_hand(input, second, "XYZ", "X, Y or Z")?,
+        ));
+    }
+    Ok(rounds)
+}
+
+fn parse_hand(input: &str, token: &str, allowed: &str, expected: &str) -> Result<char, ParseError> {
+    match token.parse::<char>() {
+        Ok(hand) if allowed.contains(hand) => Ok(hand),
+        _ => Err(ParseError::at(input, token, expected)),
+    }
+}
+
+fn convert_to_score(hand: char) -> i32 {
+    match hand {
+        'A' => 1,
+        'X' => 1,
+        'B' => 2,
+        'Y' => 2,
+        'C' => 3,
+        'Z' => 3,
+        _ => {
+            panic!("idk")
+        }
+    }
+}
+
+fn get_result_score(oppo_hand: i32, my_hand: i32) -> i32 {
+    if oppo_hand == my_hand {
+        return 3;
+    }
+    if (oppo_hand == 3 && my_hand == 1) || (oppo_hand + 1 == my_hand) {
+        return 6;
+    }
+
+    0
+}
+
+fn get_winning_hand(oppo_hand: char) -> i32 {
+    match oppo_hand {
+        'A' => 2,
+        'B' => 3,
+        'C' => 1,
+        _ => 0,
+    }
+}
+
+fn get_losing_hand(oppo_hand: char) -> i32 {
+    match oppo_hand {
+        'A' => 3,
+        'B' => 1,
+        'C' => 2,
+        _ => 0,
+    }
+}
+
+fn get_expected_score(oppo_hand: char, expected: char) -> i32 {
+    match expected {
+        'X' => get_losing_hand(oppo_hand),
+        'Y' => 3 + convert_to_score(oppo_hand),
+        'Z' => 6 + get_winning_hand(oppo_hand),
+        _ => 0,
+    }
+}
+
+// size rounds of the strategy guide
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..size.max(1) {
+        let opponent = rng.choose(&['A', 'B', 'C']);
+        let response = rng.choose(&['X', 'Y', 'Z']);
+        output += &format!("{} {}\n", opponent, response);
+    }
+    output
+}
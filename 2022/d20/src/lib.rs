@@ -0,0 +1,291 @@
+use std::{cell::RefCell, collections::HashMap, hash::Hash, hash::Hasher, rc::Rc};
+
+use aoc_common::{parse_token, ParseError, Rng};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone)]
+struct Element {
+    index: usize,
+    value: i64,
+}
+
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.value == other.value
+    }
+}
+
+impl Eq for Element {}
+
+impl Element {
+    fn new(index: usize, value: i64) -> Element {
+        Element { index, value }
+    }
+}
+
+impl Hash for Element {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+        self.value.hash(state);
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    prev: Option<Rc<RefCell<Node>>>,
+    next: Option<Rc<RefCell<Node>>>,
+    cur: Element,
+}
+
+impl Node {
+    fn new(current: Element) -> Node {
+        Node {
+            prev: None,
+            next: None,
+            cur: current,
+        }
+    }
+
+    fn get_next(&self) -> Rc<RefCell<Node>> {
+        self.next.clone().expect("next is None")
+    }
+
+    fn get_prev(&self) -> Rc<RefCell<Node>> {
+        self.prev.clone().expect("prev is None")
+    }
+}
+
+#[derive(Debug)]
+struct Game {
+    original: Vec<Element>,
+    // node: Rc<RefCell<Node>>,
+    map: HashMap<Element, Rc<RefCell<Node>>>,
+    len: i64,
+}
+
+impl Game {
+    fn new(list: Vec<i64>) -> Game {
+        let mut map = HashMap::new();
+
+        let head = Rc::new(RefCell::new(Node::new(Element::new(0, list[0]))));
+        map.insert(Element::new(0, list[0]), Rc::clone(&head));
+
+        let mut prev = Rc::clone(&head);
+
+        for (i, value) in list.iter().enumerate().skip(1) {
+            let current_element = Element::new(i, *value);
+            let node = Rc::new(RefCell::new(Node::new(current_element.clone())));
+
+            node.borrow_mut().prev = Some(Rc::clone(&prev));
+            prev.borrow_mut().next = Some(Rc::clone(&node));
+
+            map.insert(current_element, Rc::clone(&node));
+            prev = Rc::clone(&node);
+        }
+
+        head.borrow_mut().prev = Some(Rc::clone(&prev));
+        prev.borrow_mut().next = Some(Rc::clone(&head));
+
+        let element_list = list
+            .iter()
+            .enumerate()
+            .map(|(i, v)| Element::new(i, *v))
+            .collect::<Vec<Element>>();
+
+        Game {
+            original: element_list,
+            map,
+            len: list.len() as i64,
+        }
+    }
+    // direction either 0 or 1
+    // return the sum of every 1000th value after zero, up to n
+    fn cycle(&mut self, n: i64, mixing_round: i64) -> i64 {
+        let mut solution_1_ans: i64 = 0;
+        for _ in 1..=mixing_round {
+            for element in self.original.iter() {
+                if element.value == 0 {
+                    continue;
+                }
+
+                let node_to_find = self.map.get(element).unwrap();
+                let direction = if element.value > 0 { 1 } else { -1 };
+                let mut visiting_node = match element.value > 0 {
+                    true => node_to_find.borrow().get_prev(),
+                    false => node_to_find.borrow().get_next(),
+                };
+                // remove node
+                {
+                    let prev = node_to_find.borrow().get_prev();
+                    let next = node_to_find.borrow().get_next();
+                    prev.borrow_mut().next = Some(Rc::clone(&next));
+                    next.borrow_mut().prev = Some(Rc::clone(&prev));
+                    node_to_find.borrow_mut().prev = None;
+                    node_to_find.borrow_mut().next = None;
+                }
+                let mut distance_to_travel = element.value.abs() % (self.len - 1);
+                while distance_to_travel > 0 {
+                    match direction {
+                        1 => {
+                            let next_node = visiting_node.borrow_mut().get_next();
+                            visiting_node = next_node;
+                        }
+                        -1 => {
+                            let prev_node = visiting_node.borrow_mut().get_prev();
+                            visiting_node = prev_node;
+                        }
+                        _ => panic!("direction is not 1 or -1"),
+                    }
+                    distance_to_travel -= 1;
+                }
+                match direction {
+                    1 => {
+                        let to_update = visiting_node.borrow().get_next();
+                        visiting_node.borrow_mut().next = Some(Rc::clone(node_to_find));
+                        to_update.borrow_mut().prev = Some(Rc::clone(node_to_find));
+                        node_to_find.borrow_mut().prev = Some(Rc::clone(&visiting_node));
+                        node_to_find.borrow_mut().next = Some(Rc::clone(&to_update));
+                    }
+                    -1 => {
+                        let to_update = visiting_node.borrow().get_prev();
+                        visiting_node.borrow_mut().prev = Some(Rc::clone(node_to_find));
+                        to_update.borrow_mut().next = Some(Rc::clone(node_to_find));
+                        node_to_find.borrow_mut().next = Some(Rc::clone(&visiting_node));
+                        node_to_find.borrow_mut().prev = Some(Rc::clone(&to_update));
+                    }
+                    _ => panic!("direction is not 1 or -1"),
+                }
+                self.map.insert(element.clone(), Rc::clone(node_to_find));
+            }
+            // walking the whole list after every move is slow
+            if log::log_enabled!(log::Level::Trace) {
+                self.print(1);
+            }
+        }
+
+        let mut next_node = self
+            .map
+            .iter()
+            .find(|(k, _)| k.value == 0)
+            .unwrap()
+            .1
+            .clone();
+        for i in 1..=n {
+            let temp_node = next_node.borrow().get_next();
+            next_node = Rc::clone(&temp_node);
+            if i % 1000 == 0 {
+                log::debug!(
+                    "cycle: {} element after zero: {}",
+                    i,
+                    next_node.borrow().clone().cur.value
+                );
+                solution_1_ans += next_node.borrow().clone().cur.value;
+            }
+        }
+        solution_1_ans
+    }
+
+    fn print(&self, multiplier: i64) {
+        let starting_node = self.map.iter().find(|(k, _)| k.value == 0).unwrap().1;
+        let starting_index = starting_node.borrow().cur.index;
+        let mut count = 0;
+        let mut node_to_visit = starting_node.clone().borrow().get_next();
+        let mut vec_to_print = Vec::new();
+        while count == 0 {
+            vec_to_print.push(node_to_visit.borrow().cur.value * multiplier);
+            if node_to_visit.borrow().cur.index == starting_index {
+                count += 1;
+            }
+            let next_node = node_to_visit.borrow().get_next();
+            node_to_visit = next_node;
+        }
+        log::trace!("{:?}", vec_to_print);
+    }
+}
+
+pub fn read_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let mut list = Vec::new();
+
+    for line in input.lines() {
+        list.push(parse_token::<i64>(input, line, "a number")?);
+    }
+
+    // grove coordinates are counted from the 0
+    if !list.contains(&0) {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "a 0 in the file",
+        ));
+    }
+    Ok(list)
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// encrypted file, one number per line
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    read_input(input)
+}
+
+// part two applies the decryption key and mixes that many times
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub decryption_key: i64,
+    pub rounds: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            decryption_key: 811589153,
+            rounds: 10,
+        }
+    }
+}
+
+pub fn solve_part1(list: &[i64]) -> i64 {
+    solve_part1_with(list, &Params::default())
+}
+
+pub fn solve_part2(list: &[i64]) -> i64 {
+    solve_part2_with(list, &Params::default())
+}
+
+// part one is always a single round without a key
+pub fn solve_part1_with(list: &[i64], _params: &Params) -> i64 {
+    mix(list, 1, 1)
+}
+
+pub fn solve_part2_with(list: &[i64], params: &Params) -> i64 {
+    mix(list, params.decryption_key, params.rounds)
+}
+
+// sum of the grove coordinates after mixing the file
+fn mix(list: &[i64], multiplier: i64, mixing_round: i64) -> i64 {
+    let mut game = Game::new(list.iter().map(|value| value * multiplier).collect());
+    game.cycle(3000, mixing_round)
+}
+
+// size numbers with a single 0 and plenty of duplicates among the rest
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut list = Vec::new();
+    while list.len() + 1 < size {
+        let number = match !list.is_empty() && rng.one_in(5) {
+            true => *rng.choose(&list),
+            false => rng.range(-10000, 10000),
+        };
+        if number != 0 {
+            list.push(number);
+        }
+    }
+    list.insert(rng.index(list.len() + 1), 0);
+    list.iter().map(|number| format!("{}\n", number)).collect()
+}
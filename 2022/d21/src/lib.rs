@@ -0,0 +1,513 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    ops::{Add, Div, Mul, Sub},
+};
+
+use aoc_common::{next_token, parse_token, ParseError, Rng};
+use fraction::Fraction;
+use polynomial::Polynomial;
+
+#[derive(Clone, Copy, Debug)]
+#[allow(clippy::upper_case_acronyms)]
+enum Operation {
+    ADD,
+    SUB,
+    MUL,
+    DIV,
+    EQUAL,
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    identifier: String,
+    operation: Option<Operation>,
+    left: Option<(String, Option<FakeNumber>)>,
+    right: Option<(String, Option<FakeNumber>)>,
+    final_result: Option<FakeNumber>,
+}
+
+// FakeNumber is holder for mx + c
+// multiplier only affected by * and /
+#[derive(Clone, Debug)]
+struct FakeNumber {
+    value: Polynomial<Fraction>,
+}
+
+impl FakeNumber {
+    fn new(polynomial: Polynomial<Fraction>) -> FakeNumber {
+        FakeNumber { value: polynomial }
+    }
+}
+
+// multiplier to take either side which is not None
+
+impl Add for FakeNumber {
+    type Output = Self;
+
+    fn add(self, other: FakeNumber) -> Self {
+        FakeNumber {
+            value: self.value + other.value,
+        }
+    }
+}
+
+impl Mul for FakeNumber {
+    type Output = Self;
+
+    fn mul(self, other: FakeNumber) -> Self {
+        FakeNumber {
+            value: self.value * other.value,
+        }
+    }
+}
+
+impl Sub for FakeNumber {
+    type Output = Self;
+
+    fn sub(self, other: FakeNumber) -> Self {
+        FakeNumber {
+            value: self.value - other.value,
+        }
+    }
+}
+
+impl Div for FakeNumber {
+    type Output = Self;
+
+    fn div(self, other: FakeNumber) -> Self {
+        // if either side is polynomial panic
+
+        if self.value.data().len() > 1 && other.value.data().len() > 1 {
+            panic!(
+                "unable to divide {:?} by {:?}, both sides are polynomials",
+                self.value, other.value
+            );
+        } else if self.value.data().len() > 1 {
+            let inverse_other = Polynomial::new(vec![Fraction::from(1.0) / other.value.data()[0]]);
+            FakeNumber {
+                value: self.value * inverse_other,
+            }
+        } else if other.value.data().len() > 1 {
+            let mut new_inverse = vec![];
+            for coefficient in other.value.data() {
+                let new_inverse_coefficient = Fraction::from(1.0) / *coefficient;
+                new_inverse.push(new_inverse_coefficient);
+            }
+            let inverse_other = Polynomial::new(new_inverse);
+            FakeNumber {
+                value: self.value * inverse_other,
+            }
+        } else {
+            let other_inverse = Polynomial::new(vec![Fraction::from(1.0) / other.value.data()[0]]);
+            FakeNumber {
+                value: self.value * other_inverse,
+            }
+        }
+    }
+}
+
+impl Monkey {
+    fn new(
+        identifier: String,
+        operation: Option<Operation>,
+        left: Option<String>,
+        right: Option<String>,
+        final_result: Option<FakeNumber>,
+    ) -> Monkey {
+        let left_input = left.map(|left| (left, None));
+        let right_input = right.map(|right| (right, None));
+        Monkey {
+            identifier,
+            operation,
+            left: left_input,
+            right: right_input,
+            final_result,
+        }
+    }
+
+    fn can_process(&self) -> bool {
+        self.final_result.is_some()
+    }
+
+    fn update(&mut self, monkey: &Monkey) {
+        if self.final_result.is_some() {
+            return;
+        }
+        let left = self.left.as_mut().unwrap();
+        if left.0 == monkey.clone().identifier {
+            left.1 = Some(monkey.final_result.as_ref().unwrap().clone());
+        }
+
+        let right = self.right.as_mut().unwrap();
+        if right.0 == monkey.clone().identifier {
+            right.1 = Some(monkey.final_result.as_ref().unwrap().clone());
+        }
+
+        // self.left = Some(left.deref().clone());
+        // self.right = Some(right.deref().clone());
+        if self.left.as_ref().unwrap().1.is_some() && self.right.as_ref().unwrap().1.is_some() {
+            self.final_result = match self.operation {
+                Some(Operation::ADD) => Some(
+                    self.left.as_ref().unwrap().1.as_ref().unwrap().clone()
+                        + self.right.as_ref().unwrap().1.as_ref().unwrap().clone(),
+                ),
+                Some(Operation::SUB) => Some(
+                    self.left.as_ref().unwrap().1.as_ref().unwrap().clone()
+                        - self.right.as_ref().unwrap().1.as_ref().unwrap().clone(),
+                ),
+                Some(Operation::MUL) => Some(
+                    self.left.as_ref().unwrap().1.as_ref().unwrap().clone()
+                        * self.right.as_ref().unwrap().1.as_ref().unwrap().clone(),
+                ),
+                Some(Operation::DIV) => Some(
+                    self.left.as_ref().unwrap().1.as_ref().unwrap().clone()
+                        / self.right.as_ref().unwrap().1.as_ref().unwrap().clone(),
+                ),
+                Some(Operation::EQUAL) => {
+                    // get the left side and right side as an equation
+                    // eg mx + c = rhs and solve for x.
+                    log::debug!(
+                        "equation: {:?} = {:?}",
+                        self.left.as_ref().unwrap().1.as_ref().unwrap(),
+                        self.right.as_ref().unwrap().1.as_ref().unwrap()
+                    );
+                    Some(solve_equation(
+                        self.left.as_ref().unwrap().1.as_ref().unwrap(),
+                        self.right.as_ref().unwrap().1.as_ref().unwrap(),
+                    ))
+                }
+                None => panic!(),
+            };
+        }
+    }
+}
+
+struct Game {
+    map: HashMap<String, Monkey>,
+    update_map: HashMap<String, Vec<String>>,
+}
+
+impl Game {
+    fn new(monkey_list: &[Monkey]) -> Game {
+        let mut map = HashMap::<String, Monkey>::new();
+        let mut update_map = HashMap::<String, Vec<String>>::new();
+
+        for monkey in monkey_list.iter().cloned() {
+            map.insert(monkey.identifier.clone(), monkey.clone());
+
+            if monkey.final_result.is_none() {
+                let left_identifier = monkey.left.as_ref().unwrap().0.clone();
+                let right_identifier = monkey.right.as_ref().unwrap().0.clone();
+                update_map
+                    .entry(left_identifier)
+                    .or_default()
+                    .push(monkey.clone().identifier);
+                update_map
+                    .entry(right_identifier)
+                    .or_default()
+                    .push(monkey.clone().identifier);
+            }
+        }
+
+        Game { map, update_map }
+    }
+
+    // return the number yelled by root
+    fn start(&mut self) -> i64 {
+        // queue
+        // initialise monkeys with no dependencies
+
+        // on processing each monkey -> update those dependent on it -> if no longer dependant -> add to queue
+        // add check for root
+        let mut visited = HashSet::<String>::new();
+        let mut queue = VecDeque::<Monkey>::new();
+        self.map.iter().for_each(|(_, value)| {
+            if value.can_process() {
+                queue.push_back(value.clone());
+            }
+        });
+
+        while let Some(cur) = queue.pop_front() {
+            visited.insert(cur.clone().identifier);
+            if cur.identifier == "root" {
+                let root_result = self
+                    .get_monkey(&cur)
+                    .unwrap()
+                    .final_result
+                    .as_ref()
+                    .unwrap();
+                log::debug!("root: {:?}", root_result.value.data());
+                return fraction_to_i64(root_result.value.data().first().copied());
+            }
+
+            self.update_map
+                .get(&cur.clone().identifier)
+                .unwrap()
+                .iter()
+                .for_each(|monkey_to_update_identifier| {
+                    let monkey_to_update = self.map.get_mut(monkey_to_update_identifier).unwrap();
+                    monkey_to_update.update(&cur);
+
+                    if monkey_to_update.can_process()
+                        && !visited.contains(&monkey_to_update.identifier)
+                    {
+                        queue.push_back(monkey_to_update.clone());
+                    }
+                });
+        }
+
+        panic!()
+    }
+
+    fn get_monkey(&self, monkey: &Monkey) -> Option<&Monkey> {
+        let identifier = monkey.clone().identifier;
+        self.map.get(&identifier)
+    }
+}
+
+pub fn read_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkey_list = Vec::<Monkey>::new();
+    let mut operand_list = Vec::<&str>::new();
+    for line in input.lines() {
+        let splitted = line.split(' ').collect::<Vec<&str>>();
+        let identifier = match splitted[0].strip_suffix(':') {
+            Some(identifier) if !identifier.is_empty() => identifier,
+            _ => return Err(ParseError::at(input, splitted[0], "a monkey name and :")),
+        };
+        if splitted.len() == 2 {
+            let final_result = parse_token::<f64>(input, splitted[1], "a number")?;
+            monkey_list.push(Monkey::new(
+                identifier.to_string(),
+                None,
+                None,
+                None,
+                Some(FakeNumber::new(Polynomial::new(vec![Fraction::from(
+                    final_result,
+                )]))),
+            ));
+        } else {
+            let mut tokens = splitted[1..].iter().copied();
+            let left_identifier = next_token(input, line, &mut tokens, "a monkey")?;
+            let operation = next_token(input, line, &mut tokens, "an operation")?;
+            let operation = match operation {
+                "+" => Operation::ADD,
+                "-" => Operation::SUB,
+                "*" => Operation::MUL,
+                "/" => Operation::DIV,
+                "=" => Operation::EQUAL,
+                _ => return Err(ParseError::at(input, operation, "+, -, * or /")),
+            };
+            let right_identifier = next_token(input, line, &mut tokens, "a monkey")?;
+            if let Some(token) = tokens.next() {
+                return Err(ParseError::at(input, token, "end of line"));
+            }
+            operand_list.extend([left_identifier, right_identifier]);
+            monkey_list.push(Monkey::new(
+                identifier.to_string(),
+                Some(operation),
+                Some(left_identifier.to_string()),
+                Some(right_identifier.to_string()),
+                None,
+            ));
+        }
+    }
+
+    // every operand has to be yelled by some monkey, starting from root
+    let identifier_set = monkey_list
+        .iter()
+        .map(|monkey| monkey.identifier.as_str())
+        .collect::<HashSet<&str>>();
+    if !identifier_set.contains("root") {
+        return Err(ParseError::new(1, 1, "a root monkey"));
+    }
+    if let Some(operand) = operand_list.iter().find(|e| !identifier_set.contains(*e)) {
+        return Err(ParseError::at(input, operand, "a monkey with its own line"));
+    }
+    Ok(monkey_list)
+}
+
+// solve mx + c = rhs for x, where only one side holds x
+fn solve_equation(left: &FakeNumber, right: &FakeNumber) -> FakeNumber {
+    let (equation, rhs) = match left.value.data().len() > 1 {
+        true => (left.value.data(), right.value.data()),
+        false => (right.value.data(), left.value.data()),
+    };
+    let c = equation[0];
+    let m = equation[1];
+    let rhs = rhs.first().copied().unwrap_or(Fraction::from(0));
+
+    FakeNumber::new(Polynomial::new(vec![(rhs - c) / m]))
+}
+
+fn fraction_to_i64(value: Option<Fraction>) -> i64 {
+    let value = match value {
+        Some(value) => value,
+        None => return 0,
+    };
+    let result = (*value.numer().unwrap() / *value.denom().unwrap()) as i64;
+    if value.is_sign_negative() {
+        -result
+    } else {
+        result
+    }
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// monkeys with either a number or a pending operation
+pub fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    read_input(input)
+}
+
+// number yelled by root
+pub fn solve_part1(monkey_list: &[Monkey]) -> i64 {
+    let mut game = Game::new(monkey_list);
+    game.start()
+}
+
+// number humn has to yell for root's equality check to pass
+pub fn solve_part2(monkey_list: &[Monkey]) -> i64 {
+    // humn becomes x and root checks that both sides are equal
+    let mut new_monkey_list = monkey_list
+        .iter()
+        .filter(|monkey| monkey.identifier != "humn")
+        .cloned()
+        .map(|mut monkey| {
+            if monkey.identifier == "root" {
+                monkey.operation = Some(Operation::EQUAL);
+            }
+            monkey
+        })
+        .collect::<Vec<Monkey>>();
+    let new_monkey = Monkey::new(
+        "humn".to_string(),
+        None,
+        None,
+        None,
+        Some(FakeNumber::new(Polynomial::new(vec![
+            Fraction::from(0),
+            Fraction::from(1),
+        ]))),
+    );
+    new_monkey_list.push(new_monkey);
+    let mut game_2 = Game::new(&new_monkey_list);
+    game_2.start()
+}
+
+// about size monkeys. humn shows up once and never as a divisor, and the
+// other side of root is whatever the humn side comes to when humn yells
+// the answer of part two, so both parts work out to whole numbers
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut names = HashSet::from(["root".to_string(), "humn".to_string()]);
+    let mut jobs = Vec::new();
+    let humn = rng.range(1, 1000);
+    let answer = rng.range(1, 1000);
+
+    // (value with humn, value with answer) of the chain from humn upwards
+    let mut chain = "humn".to_string();
+    let mut values = (humn, answer);
+    for _ in 0..size.max(2) / 4 {
+        let constant = rng.range(1, 5);
+        let operand = generate_monkey(rng, &mut names, &mut jobs, 1, constant);
+        let name = new_name(rng, &mut names);
+        let divides = values.0 % constant == 0 && values.1 % constant == 0;
+        let job = match rng.index(5) {
+            0 => {
+                values = (values.0 + constant, values.1 + constant);
+                format!("{}: {} + {}", name, chain, operand)
+            }
+            1 => {
+                values = (values.0 - constant, values.1 - constant);
+                format!("{}: {} - {}", name, chain, operand)
+            }
+            2 => {
+                values = (constant - values.0, constant - values.1);
+                format!("{}: {} - {}", name, operand, chain)
+            }
+            3 if divides => {
+                values = (values.0 / constant, values.1 / constant);
+                format!("{}: {} / {}", name, chain, operand)
+            }
+            _ if values.0.abs().max(values.1.abs()) < 1_000_000_000 => {
+                values = (values.0 * constant, values.1 * constant);
+                format!("{}: {} * {}", name, operand, chain)
+            }
+            _ => {
+                values = (values.0 + constant, values.1 + constant);
+                format!("{}: {} + {}", name, operand, chain)
+            }
+        };
+        jobs.push(job);
+        chain = name;
+    }
+
+    let budget = size.max(2) - size.max(2) / 2;
+    let other = generate_monkey(rng, &mut names, &mut jobs, budget, values.1);
+    match rng.one_in(2) {
+        true => jobs.push(format!("root: {} + {}", chain, other)),
+        false => jobs.push(format!("root: {} + {}", other, chain)),
+    }
+    jobs.push(format!("humn: {}", humn));
+    rng.shuffle(&mut jobs);
+    jobs.iter().map(|job| format!("{}\n", job)).collect()
+}
+
+// monkey yelling value, with up to budget monkeys below it. numbers yelled
+// outright are always positive
+fn generate_monkey(
+    rng: &mut Rng,
+    names: &mut HashSet<String>,
+    jobs: &mut Vec<String>,
+    budget: usize,
+    value: i64,
+) -> String {
+    let name = new_name(rng, names);
+    if value > 0 && (budget <= 1 || rng.one_in(4)) {
+        jobs.push(format!("{}: {}", name, value));
+        return name;
+    }
+    let budget = budget.saturating_sub(1);
+    let (left_budget, right_budget) = (budget / 2, budget - budget / 2);
+    let divisors = (2..=5)
+        .filter(|divisor| value % divisor == 0)
+        .collect::<Vec<_>>();
+    let (left, operation, right) = match rng.index(4) {
+        0 if value > 1 => {
+            let left = rng.range(1, value - 1);
+            (left, '+', value - left)
+        }
+        1 if !divisors.is_empty() => {
+            let divisor = *rng.choose(&divisors);
+            (value / divisor, '*', divisor)
+        }
+        2 if value.abs() < 1_000_000_000 => {
+            let divisor = rng.range(1, 5);
+            (value * divisor, '/', divisor)
+        }
+        _ => {
+            let right = rng.range((1 - value).max(1), (1 - value).max(1) + 1000);
+            (value + right, '-', right)
+        }
+    };
+    let left = generate_monkey(rng, names, jobs, left_budget, left);
+    let right = generate_monkey(rng, names, jobs, right_budget, right);
+    jobs.push(format!("{}: {} {} {}", name, left, operation, right));
+    name
+}
+
+fn new_name(rng: &mut Rng, names: &mut HashSet<String>) -> String {
+    loop {
+        let name = (0..4)
+            .map(|_| (b'a' + rng.index(26) as u8) as char)
+            .collect::<String>();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
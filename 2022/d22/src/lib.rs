@@ -0,0 +1,550 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{parse_token, Grid, ParseError, Rng};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up = 3,
+    Right = 0,
+    Down = 1,
+    Left = 2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Block {
+    Wall,
+    Path,
+    Void,
+}
+
+impl Direction {
+    fn get_clockwise(&self) -> Direction {
+        let new_value = ((*self as i32) + 1) % 4;
+        get_direction(new_value)
+    }
+
+    fn get_counter_clockwise(&self) -> Direction {
+        let new_value = ((*self as i32) - 1 + 4) % 4;
+        get_direction(new_value)
+    }
+}
+
+fn get_direction(value: i32) -> Direction {
+    match value {
+        3 => Direction::Up,
+        0 => Direction::Right,
+        1 => Direction::Down,
+        2 => Direction::Left,
+        _ => unreachable!(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Position {
+    x: i32,
+    y: i32,
+    direction: Direction,
+}
+
+impl Position {
+    fn change_direction(&mut self, is_clockwise: bool) {
+        self.direction = if is_clockwise {
+            self.direction.get_clockwise()
+        } else {
+            self.direction.get_counter_clockwise()
+        }
+    }
+
+    fn get_next_position(&self, boundary: (i32, i32, i32, i32)) -> Position {
+        let mut new_position = self.clone();
+        match self.direction {
+            Direction::Up => new_position.y -= 1,
+            Direction::Down => new_position.y += 1,
+            Direction::Left => new_position.x -= 1,
+            Direction::Right => new_position.x += 1,
+        }
+        if new_position.x < boundary.0 {
+            new_position.x = boundary.1;
+        } else if new_position.x > boundary.1 {
+            new_position.x = boundary.0;
+        } else if new_position.y < boundary.2 {
+            new_position.y = boundary.3;
+        } else if new_position.y > boundary.3 {
+            new_position.y = boundary.2;
+        }
+        new_position
+    }
+}
+
+pub trait Instruction {
+    fn execute(
+        &self,
+        position: &Position,
+        position_helper: &HashMap<Position, Position>,
+    ) -> Position;
+}
+
+#[derive(Debug)]
+struct MoveMechanic {
+    magnitude: i32,
+}
+
+#[derive(Debug)]
+struct TurnMechanic {
+    is_clockwise: bool,
+}
+
+impl Instruction for MoveMechanic {
+    // return final position
+    fn execute(
+        &self,
+        position: &Position,
+        position_helper: &HashMap<Position, Position>,
+    ) -> Position {
+        log::trace!("moving {}", self.magnitude);
+        let mut final_position = position.clone();
+        for _ in 0..self.magnitude {
+            let new_position = final_position.clone();
+            log::trace!("at {:?}", new_position);
+            final_position = match position_helper.get(&new_position) {
+                Some(next_position) => next_position.clone(),
+                None => {
+                    break;
+                }
+            }
+        }
+        final_position
+    }
+}
+
+impl Instruction for TurnMechanic {
+    fn execute(&self, position: &Position, _: &HashMap<Position, Position>) -> Position {
+        let mut final_position = position.clone();
+        final_position.change_direction(self.is_clockwise);
+        final_position
+    }
+}
+
+pub struct Map {
+    map: Grid<Block>,
+    position_helper: HashMap<Position, Position>,
+}
+
+impl Map {
+    pub fn new(matrix: Grid<Block>) -> Map {
+        let mut position_helper = HashMap::new();
+        let (min_x, max_x) = (0, matrix.width() as i32 - 1);
+        let (min_y, max_y) = (0, matrix.height() as i32 - 1);
+
+        // if next is a path, add to hashmap
+        // else if next is a wall. add None
+        // else if next is a void, continue to next until ^
+
+        matrix.iter().for_each(|((x, y), block)| {
+            if block == &Block::Path {
+                for direction in 0..=3 {
+                    let mut checking_position = Position {
+                        x,
+                        y,
+                        direction: get_direction(direction),
+                    };
+                    loop {
+                        let next_position =
+                            checking_position.get_next_position((min_x, max_x, min_y, max_y));
+                        match matrix.get((next_position.x, next_position.y)) {
+                            Some(Block::Path) => {
+                                position_helper.insert(
+                                    Position {
+                                        x,
+                                        y,
+                                        direction: get_direction(direction),
+                                    },
+                                    next_position,
+                                );
+                                break;
+                            }
+                            Some(Block::Wall) => {
+                                break;
+                            }
+                            Some(Block::Void) => {
+                                checking_position = next_position;
+                            }
+                            None => {
+                                unreachable!()
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        Map {
+            map: matrix,
+            position_helper,
+        }
+    }
+
+    // same map but walking off an edge continues on the folded cube, which
+    // the board has to be the net of
+    pub fn new_cube(matrix: Grid<Block>) -> Result<Map, ParseError> {
+        let position_helper = init_cube(&matrix)?;
+
+        Ok(Map {
+            map: matrix,
+            position_helper,
+        })
+    }
+
+    // final password after following every instruction
+    pub fn start(&self, instruction_list: &[Box<dyn Instruction>]) -> i32 {
+        // leftmost open tile of the top row
+        let (x, y) = self.map.position(|block| *block == Block::Path).unwrap();
+        let mut position = Position {
+            x,
+            y,
+            direction: Direction::Right,
+        };
+
+        for instruction in instruction_list {
+            position = instruction.execute(&position, &self.position_helper);
+        }
+
+        (position.y + 1) * 1000 + (position.x + 1) * 4 + (position.direction as i32)
+    }
+}
+
+type Instructions = Vec<Box<dyn Instruction>>;
+
+// board and the path to walk on it
+pub struct Notes {
+    map: Grid<Block>,
+    instruction_list: Instructions,
+}
+
+pub fn read_input(content: &str) -> Result<Notes, ParseError> {
+    let (map_content, instruction_content) = match content.split_once("\n\n") {
+        Some((map_content, instruction_content)) => (map_content, instruction_content.trim()),
+        None => {
+            let end = &content[content.len()..];
+            return Err(ParseError::at(content, end, "a blank line before the path"));
+        }
+    };
+    // rows leave out the void after the last tile
+    let map = Grid::parse_ragged(map_content, "#, . or a space", Block::Void, |c| match c {
+        '#' => Some(Block::Wall),
+        '.' => Some(Block::Path),
+        ' ' => Some(Block::Void),
+        _ => None,
+    })?;
+    if log::log_enabled!(log::Level::Trace) {
+        for ((x, y), block) in map.iter() {
+            log::trace!("x: {}, y: {}, block: {:?}", x, y, block);
+        }
+    }
+    let mut instruction_list = Vec::new();
+    let mut start = 0;
+    for (index, c) in instruction_content.char_indices() {
+        if !c.is_ascii_digit() {
+            let steps = &instruction_content[start..index];
+            let magnitude = Box::new(MoveMechanic {
+                magnitude: parse_token::<i32>(content, steps, "a number of steps")?,
+            }) as Box<dyn Instruction>;
+            let turn_instruction = match c {
+                'R' => Box::new(TurnMechanic { is_clockwise: true }) as Box<dyn Instruction>,
+                'L' => Box::new(TurnMechanic {
+                    is_clockwise: false,
+                }) as Box<dyn Instruction>,
+                _ => {
+                    let token = &instruction_content[index..index + c.len_utf8()];
+                    return Err(ParseError::at(content, token, "R or L"));
+                }
+            };
+            instruction_list.push(magnitude);
+            instruction_list.push(turn_instruction);
+
+            start = index + 1;
+        }
+    }
+    if start < instruction_content.len() {
+        let steps = &instruction_content[start..];
+        let magnitude = Box::new(MoveMechanic {
+            magnitude: parse_token::<i32>(content, steps, "a number of steps")?,
+        }) as Box<dyn Instruction>;
+        instruction_list.push(magnitude);
+    }
+
+    // the walk starts on the leftmost open tile of the top row
+    if !map_content
+        .lines()
+        .next()
+        .is_some_and(|line| line.contains('.'))
+    {
+        return Err(ParseError::new(1, 1, "an open tile on the top row"));
+    }
+    Ok(Notes {
+        map,
+        instruction_list,
+    })
+}
+
+type Vector = [i32; 3];
+
+fn negate(v: Vector) -> Vector {
+    [-v[0], -v[1], -v[2]]
+}
+
+fn add(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(v: Vector, n: i32) -> Vector {
+    [v[0] * n, v[1] * n, v[2] * n]
+}
+
+fn dot(a: Vector, b: Vector) -> i32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn is_face(matrix: &Grid<Block>, face: (i32, i32), size: i32) -> bool {
+    face.0 >= 0
+        && face.1 >= 0
+        && matches!(
+            matrix.get((face.0 * size, face.1 * size)),
+            Some(Block::Path) | Some(Block::Wall)
+        )
+}
+
+// (normal, right, down) of every face of the net once folded into a cube
+type Faces = HashMap<(i32, i32), (Vector, Vector, Vector)>;
+
+// the faces of the net with the length of their sides. every tile has to
+// be on one of six square faces that fold into a cube without overlapping
+fn fold(matrix: &Grid<Block>) -> Result<(i32, Faces), ParseError> {
+    let cells = matrix
+        .iter()
+        .filter(|(_, block)| **block != Block::Void)
+        .count();
+    let size = ((cells / 6) as f64).sqrt() as i32;
+    if size == 0 || 6 * (size * size) as usize != cells {
+        return Err(ParseError::new(1, 1, "a cube net of six square faces"));
+    }
+    let expected = || format!("a face of {} by {} tiles", size, size);
+    let at = |(x, y): (i32, i32), expected: String| {
+        ParseError::new(y as usize + 1, x as usize + 1, expected)
+    };
+
+    // every tile on a face whole, so that there are six of them
+    let mut net = Vec::new();
+    for ((x, y), block) in matrix.iter() {
+        let face = (x / size, y / size);
+        if *block == Block::Void || net.contains(&face) {
+            continue;
+        }
+        let corner = (face.0 * size, face.1 * size);
+        for dy in 0..size {
+            for dx in 0..size {
+                let tile = (corner.0 + dx, corner.1 + dy);
+                if !matches!(matrix.get(tile), Some(Block::Path) | Some(Block::Wall)) {
+                    return Err(at(tile, expected()));
+                }
+            }
+        }
+        net.push(face);
+    }
+
+    // paint outward facing side
+    let start_face = net[0];
+    let mut faces = Faces::new();
+    faces.insert(start_face, ([0, 0, 1], [1, 0, 0], [0, 1, 0]));
+    let mut queue = VecDeque::from([start_face]);
+    while let Some(face) = queue.pop_front() {
+        let (normal, right, down) = faces[&face];
+        // folding over an edge turns the normal towards that edge
+        let neighbours = [
+            ((face.0 + 1, face.1), (right, negate(normal), down)),
+            ((face.0 - 1, face.1), (negate(right), normal, down)),
+            ((face.0, face.1 + 1), (down, right, negate(normal))),
+            ((face.0, face.1 - 1), (negate(down), right, normal)),
+        ];
+        for (next_face, frame) in neighbours {
+            if faces.contains_key(&next_face) || !is_face(matrix, next_face, size) {
+                continue;
+            }
+            // two faces folded onto the same side
+            if faces.values().any(|(normal, _, _)| *normal == frame.0) {
+                let corner = (next_face.0 * size, next_face.1 * size);
+                return Err(at(corner, "a cube net".to_string()));
+            }
+            faces.insert(next_face, frame);
+            queue.push_back(next_face);
+        }
+    }
+    if let Some(face) = net.iter().find(|face| !faces.contains_key(face)) {
+        let corner = (face.0 * size, face.1 * size);
+        return Err(at(corner, "a face next to the others".to_string()));
+    }
+    Ok((size, faces))
+}
+
+fn init_cube(matrix: &Grid<Block>) -> Result<HashMap<Position, Position>, ParseError> {
+    let (size, faces) = fold(matrix)?;
+
+    // combine the cubes
+    let mut position_helper = HashMap::new();
+    for ((x, y), block) in matrix.iter() {
+        if *block != Block::Path {
+            continue;
+        }
+        for direction in 0..=3 {
+            let position = Position {
+                x,
+                y,
+                direction: get_direction(direction),
+            };
+            let next_position = get_next_position_on_cube(&position, &faces, size);
+            if matrix.get((next_position.x, next_position.y)) == Some(&Block::Path) {
+                position_helper.insert(position, next_position);
+            }
+        }
+    }
+    Ok(position_helper)
+}
+
+// cube is centred on the origin with cells two units wide, so a face sits at normal * size
+fn get_next_position_on_cube(position: &Position, faces: &Faces, size: i32) -> Position {
+    let face = (position.x / size, position.y / size);
+    let next_position = position.get_next_position((i32::MIN, i32::MAX, i32::MIN, i32::MAX));
+    if next_position.x >= 0
+        && next_position.y >= 0
+        && (next_position.x / size, next_position.y / size) == face
+    {
+        return next_position;
+    }
+
+    let (normal, right, down) = faces[&face];
+    let moving = match position.direction {
+        Direction::Right => right,
+        Direction::Left => negate(right),
+        Direction::Down => down,
+        Direction::Up => negate(down),
+    };
+    let centre = add(
+        scale(normal, size),
+        add(
+            scale(right, 2 * (position.x % size) + 1 - size),
+            scale(down, 2 * (position.y % size) + 1 - size),
+        ),
+    );
+    // step onto the edge and down the side of the next face
+    let next_centre = add(add(centre, moving), negate(normal));
+
+    let (&next_face, &(_, next_right, next_down)) = faces
+        .iter()
+        .find(|(_, (next_normal, _, _))| *next_normal == moving)
+        .unwrap();
+    let heading = negate(normal);
+    let direction = if heading == next_right {
+        Direction::Right
+    } else if heading == negate(next_right) {
+        Direction::Left
+    } else if heading == next_down {
+        Direction::Down
+    } else {
+        Direction::Up
+    };
+
+    Position {
+        x: next_face.0 * size + (dot(next_centre, next_right) + size - 1) / 2,
+        y: next_face.1 * size + (dot(next_centre, next_down) + size - 1) / 2,
+        direction,
+    }
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?)?.to_string())
+}
+
+pub fn parse(input: &str) -> Result<Notes, ParseError> {
+    read_input(input)
+}
+
+// password when walking off an edge wraps around the flat board
+pub fn solve_part1(notes: &Notes) -> i32 {
+    let game = Map::new(notes.map.clone());
+    game.start(&notes.instruction_list)
+}
+
+// password when the board is folded into a cube, an error when it does
+// not fold into one
+pub fn solve_part2(notes: &Notes) -> Result<i32, ParseError> {
+    let game = Map::new_cube(notes.map.clone())?;
+    Ok(game.start(&notes.instruction_list))
+}
+
+// the eleven nets of a cube, # for a face
+const NETS: [&[&str]; 11] = [
+    &["#...", "####", "#..."],
+    &["#...", "####", ".#.."],
+    &["#...", "####", "..#."],
+    &["#...", "####", "...#"],
+    &[".#..", "####", ".#.."],
+    &[".#..", "####", "..#."],
+    &["##..", ".###", ".#.."],
+    &["##..", ".###", "..#."],
+    &["##..", ".###", "...#"],
+    &["##..", ".##.", "..##"],
+    &["###..", "..###"],
+];
+
+// a net picked at random, turned and mirrored, with faces of size tiles,
+// walls on an eighth of them and a path of size moves
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(2);
+    let mut net = rng
+        .choose(&NETS)
+        .iter()
+        .map(|row| row.chars().map(|c| c == '#').collect::<Vec<bool>>())
+        .collect::<Vec<_>>();
+    if rng.one_in(2) {
+        net = (0..net[0].len())
+            .map(|x| net.iter().map(|row| row[x]).collect())
+            .collect();
+    }
+    if rng.one_in(2) {
+        net.reverse();
+    }
+    if rng.one_in(2) {
+        net.iter_mut().for_each(|row| row.reverse());
+    }
+
+    let mut rows = Vec::new();
+    for faces in net.iter() {
+        for _ in 0..side {
+            let mut row = String::new();
+            for &face in faces {
+                for _ in 0..side {
+                    row.push(match (face, rng.one_in(8)) {
+                        (false, _) => ' ',
+                        (true, true) => '#',
+                        (true, false) => '.',
+                    });
+                }
+            }
+            rows.push(row.trim_end().to_string());
+        }
+    }
+    // the walk starts on the leftmost tile of the top row
+    let start = rows[0].find(|c| c != ' ').unwrap();
+    rows[0].replace_range(start..start + 1, ".");
+
+    let mut path = rng.range(1, 2 * side as i64).to_string();
+    for _ in 1..size.max(1) {
+        let turn = rng.choose(&['L', 'R']);
+        path += &format!("{}{}", turn, rng.range(1, 2 * side as i64));
+    }
+    rows.join("\n") + "\n\n" + &path + "\n"
+}
@@ -0,0 +1,390 @@
+use std::collections::HashMap;
+
+use aoc_common::{Frame, FrameSink, Grid, ParseError, Rng};
+
+// ground added on every side once an elf gets close to the edge of the grid
+const MARGIN: usize = 10;
+
+#[derive(Clone, Copy, PartialOrd, PartialEq, Debug)]
+enum Movement {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+fn get_all_neighbours(x: i32, y: i32) -> Vec<Coord> {
+    vec![
+        Coord { x: x - 1, y: y - 1 },
+        Coord { x, y: y - 1 },
+        Coord { x: x + 1, y: y - 1 },
+        Coord { x: x + 1, y },
+        Coord { x: x + 1, y: y + 1 },
+        Coord { x, y: y + 1 },
+        Coord { x: x - 1, y: y + 1 },
+        Coord { x: x - 1, y },
+    ]
+}
+
+// return top, left, right, bottom or all
+fn validate_next_pos(
+    x: i32,
+    y: i32,
+    movement: &Movement,
+    neighbour_set: &Grid<bool>,
+) -> Option<Coord> {
+    let init_movement = get_all_neighbours(x, y);
+
+    let movement_to_validate = match movement {
+        Movement::Up => init_movement[0..3].to_vec(),
+        Movement::Right => init_movement[2..5].to_vec(),
+        Movement::Down => init_movement[4..7].to_vec(),
+        Movement::Left => {
+            let mut left = init_movement[6..8].to_vec();
+            left.push(init_movement[0]);
+            left
+        }
+    };
+
+    // if there is no neighbour found in the path to go -> return Some(movement list)
+    if movement_to_validate
+        .iter()
+        .filter(|&coord| is_elf(neighbour_set, coord))
+        .count()
+        .eq(&0)
+    {
+        let movement_to_go = match movement {
+            Movement::Up => init_movement[1],
+            Movement::Right => init_movement[3],
+            Movement::Down => init_movement[5],
+            Movement::Left => init_movement[7],
+        };
+        Some(movement_to_go)
+    } else {
+        None
+    }
+}
+
+#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
+struct Coord {
+    x: i32,
+    y: i32,
+}
+
+fn is_elf(neighbour_set: &Grid<bool>, coord: &Coord) -> bool {
+    neighbour_set.get((coord.x, coord.y)) == Some(&true)
+}
+
+#[derive(Debug)]
+struct Elf {
+    coord: Coord,
+    move_priority: Vec<Movement>,
+    next_coord: Option<Coord>,
+}
+
+impl Elf {
+    fn new(x: i32, y: i32) -> Elf {
+        // let move_priority = vec![
+        //     (0, 0 - 1),
+        //     (0, 0 + 1),
+        //     (0 - 1, 0),
+        //     (0 + 1, 0),];
+
+        let move_priority = vec![
+            Movement::Up,
+            Movement::Down,
+            Movement::Left,
+            Movement::Right,
+        ];
+        Elf {
+            coord: Coord { x, y },
+            move_priority,
+            next_coord: None,
+        }
+    }
+
+    fn round_one(&mut self, neighbour_set: &Grid<bool>) {
+        // only allow processing if there is neighbour
+        let all_neighbour_coord = get_all_neighbours(self.coord.x, self.coord.y);
+
+        // get the movement and updated it to next coord (only proceed if there is neighbour)
+        if all_neighbour_coord
+            .iter()
+            .filter(|&coord| is_elf(neighbour_set, coord))
+            .count()
+            .gt(&0)
+        {
+            if let Some((_index, movement)) =
+                self.move_priority
+                    .iter()
+                    .enumerate()
+                    .find(|&(_, movement)| {
+                        validate_next_pos(self.coord.x, self.coord.y, movement, neighbour_set)
+                            .is_some()
+                    })
+            {
+                let movement = *movement; // take a copy of the movement
+                self.next_coord =
+                    validate_next_pos(self.coord.x, self.coord.y, &movement, neighbour_set);
+
+                // if index + 1 < self.move_priority.len() {
+                //     self.move_priority.rotate_left(index + 1);
+                // }
+            }
+        }
+
+        let first = self.move_priority.remove(0);
+        self.move_priority.push(first);
+    }
+
+    // apply coord given that it is not in the set
+    fn round_two(&mut self, coord_counter: &HashMap<Coord, i32>) {
+        if let Some(coord) = self.next_coord {
+            match coord_counter.get(&coord) {
+                Some(x) => {
+                    if *x == 1 {
+                        self.coord = coord;
+                    }
+                    // Reset it
+                    self.next_coord = None;
+                }
+                None => {
+                    panic!("coord have to be already counted in the counter");
+                }
+            }
+        }
+    }
+}
+
+struct Game {
+    elf_list: Vec<Elf>,
+    // elf positions, grown as the elves spread out
+    ground: Grid<bool>,
+    // how far the ground has grown to the top and left, which moves every
+    // elf away from where it was in the scan
+    offset: i32,
+}
+
+impl Game {
+    fn new(scan: &Grid<Tile>) -> Game {
+        let init_elf = scan
+            .iter()
+            .filter_map(|((x, y), tile)| match tile {
+                Tile::Elf => Some(Elf::new(x, y)),
+                _ => None,
+            })
+            .collect::<Vec<Elf>>();
+        let ground = scan.map(|tile| matches!(tile, Tile::Elf));
+        Game {
+            elf_list: init_elf,
+            ground,
+            offset: 0,
+        }
+    }
+
+    // elves move at most one tile a round and look one tile further, so
+    // keep two tiles of ground between them and the edge
+    fn grow_ground(&mut self) {
+        let width = self.ground.width() as i32;
+        let height = self.ground.height() as i32;
+        let near_edge = self.elf_list.iter().any(|elf| {
+            elf.coord.x < 2
+                || elf.coord.y < 2
+                || elf.coord.x >= width - 2
+                || elf.coord.y >= height - 2
+        });
+        if near_edge {
+            self.ground = self.ground.padded(MARGIN, false);
+            self.offset += MARGIN as i32;
+            for elf in self.elf_list.iter_mut() {
+                elf.coord.x += MARGIN as i32;
+                elf.coord.y += MARGIN as i32;
+            }
+        }
+    }
+
+    fn get_elves_coord_set(&self) -> Grid<bool> {
+        let mut elves_coord_set = Grid::new(self.ground.width(), self.ground.height(), false);
+        for elf in self.elf_list.iter() {
+            elves_coord_set[(elf.coord.x, elf.coord.y)] = true;
+        }
+        elves_coord_set
+    }
+
+    // false when no elf moved
+    fn play_round(&mut self) -> bool {
+        // recompute coord set every round
+        self.grow_ground();
+        let elves_coord_set = self.get_elves_coord_set();
+
+        let mut counter = HashMap::new();
+        for elf in self.elf_list.iter_mut() {
+            elf.round_one(&elves_coord_set);
+            // remove error on unwrap with None -> panic
+            if let Some(coord) = elf.next_coord {
+                let count = counter.entry(coord).or_insert(0);
+                *count += 1;
+            }
+        }
+
+        for elf in self.elf_list.iter_mut() {
+            elf.round_two(&counter);
+        }
+        self.ground = self.get_elves_coord_set();
+        elves_coord_set != self.ground
+    }
+
+    fn play_n_rounds(&mut self, n: i32) {
+        self.debug_print();
+        for _ in 0..n {
+            self.play_round();
+            self.debug_print();
+        }
+    }
+
+    // return the first round where no elf moves
+    fn round_2_play_until_no_movement(&mut self) -> i32 {
+        let n = 100000;
+        for round in 0..n {
+            if !self.play_round() {
+                log::debug!("round: {}", round + 1);
+                return round + 1;
+            }
+        }
+        panic!("elves are still moving after {} rounds", n);
+    }
+
+    // elves where they would be in the scan, following the middle of the
+    // group as it spreads out
+    fn get_frame(&self) -> Frame {
+        let mut frame = Frame::new();
+        for elf in &self.elf_list {
+            frame.set((elf.coord.x - self.offset, elf.coord.y - self.offset), '#');
+        }
+        if let Some(((min_x, min_y), (max_x, max_y))) = frame.bounds() {
+            frame.focus_on(((min_x + max_x) / 2, (min_y + max_y) / 2));
+        }
+        frame
+    }
+
+    fn debug_print(&self) {
+        if log::log_enabled!(log::Level::Trace) {
+            log::trace!(
+                "\n{}",
+                self.ground.render(|&elf| if elf { '#' } else { '.' })
+            );
+        }
+    }
+
+    // for getting answer of solution one
+    fn print_star_one_ans(&self) -> i32 {
+        let grid_list = self.elf_list.iter().collect::<Vec<_>>();
+        let (mut mn_x, mut mn_y, mut mx_x, mut mx_y) = (
+            grid_list[0].coord.x,
+            grid_list[0].coord.y,
+            grid_list[0].coord.x,
+            grid_list[0].coord.y,
+        );
+        for &elf in grid_list.iter() {
+            let x = elf.coord.x;
+            let y = elf.coord.y;
+            if x < mn_x {
+                mn_x = x;
+            }
+            if y < mn_y {
+                mn_y = y;
+            }
+            if x > mx_x {
+                mx_x = x;
+            }
+            if y > mx_y {
+                mx_y = y;
+            }
+        }
+        log::debug!("x: {}", mx_x + 1 - mn_x);
+        log::debug!("y: {}", mx_y + 1 - mn_y);
+        (mx_x + 1 - mn_x) * (mx_y + 1 - mn_y) - self.elf_list.len() as i32
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum Tile {
+    Elf,
+    Empty,
+}
+
+pub fn handle_input(content: &str) -> Result<Grid<Tile>, ParseError> {
+    let scan = Grid::parse(content, "# or .", |c| match c {
+        '.' => Some(Tile::Empty),
+        '#' => Some(Tile::Elf),
+        _ => None,
+    })?;
+
+    if scan.position(|tile| matches!(tile, Tile::Elf)).is_none() {
+        return Err(ParseError::new(1, 1, "at least one elf"));
+    }
+    Ok(scan)
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// every tile of the scan
+pub fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+    handle_input(input)
+}
+
+// empty ground in the bounding rectangle after 10 rounds
+pub fn solve_part1(scan: &Grid<Tile>) -> i32 {
+    let mut game = Game::new(scan);
+    game.play_n_rounds(10);
+    game.print_star_one_ans()
+}
+
+// first round in which no elf moves
+pub fn solve_part2(scan: &Grid<Tile>) -> i32 {
+    let mut game = Game::new(scan);
+    game.round_2_play_until_no_movement()
+}
+
+// one frame per round, the first ten of part one or every round until the
+// elves stop moving for part two
+pub fn replay(scan: &Grid<Tile>, part: u8, sink: &mut dyn FrameSink) {
+    let mut game = Game::new(scan);
+    let mut round = 0;
+    let mut moved = true;
+    loop {
+        let done = !moved || (part == 1 && round == 10);
+        if sink.wants_frame() || (done && sink.final_only()) {
+            let mut frame = game.get_frame();
+            frame.set_caption(format!("round {}", round));
+            sink.frame(&frame);
+        }
+        if done {
+            break;
+        }
+        moved = game.play_round();
+        round += 1;
+    }
+}
+
+// size by size scan with an elf on about half the tiles
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    let mut rows = Vec::new();
+    for _ in 0..side {
+        let row = (0..side)
+            .map(|_| if rng.one_in(2) { '#' } else { '.' })
+            .collect::<String>();
+        rows.push(row);
+    }
+    if !rows.iter().any(|row| row.contains('#')) {
+        rows[0].replace_range(0..1, "#");
+    }
+    rows.iter().map(|row| format!("{}\n", row)).collect()
+}
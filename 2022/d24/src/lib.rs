@@ -0,0 +1,449 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_common::{Frame, FrameSink, Grid, ParseError, Rng};
+
+pub enum Object {
+    Wall,
+    Empty,
+    Wind(Direction),
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
+struct Coord {
+    x: i32,
+    y: i32,
+}
+
+pub struct Game {
+    wind_state: HashSet<(Coord, Direction)>, // O(1) lookup
+    wall_state: Grid<bool>,
+    mx_x: i32,
+    mx_y: i32,
+}
+
+impl Game {
+    fn new(input: &Grid<Object>) -> Game {
+        let mut wind_state = HashSet::new();
+        for ((x, y), object) in input.iter() {
+            if let Object::Wind(direction) = object {
+                wind_state.insert((Coord { x, y }, *direction));
+            }
+        }
+        // anything off the map counts as a wall, which seals the entrances
+        let wall_state = input.map(|object| matches!(object, Object::Wall));
+
+        let mx_x = input.width() as i32 - 1;
+        let mx_y = input.height() as i32 - 1;
+
+        Game {
+            wind_state,
+            wall_state,
+            mx_x,
+            mx_y,
+        }
+    }
+
+    fn run(
+        &self,
+        start: &Coord,
+        end: &Coord,
+        wind_state_mapper: &HashMap<i32, Grid<bool>>,
+        round: i32,
+    ) -> Result<i32, ParseError> {
+        // carry out bfs, avoid going past route(wait instead)
+        let mut seen = HashSet::<(Coord, i32)>::new();
+        let mut queue = VecDeque::<(Coord, i32)>::new();
+        queue.push_back((*start, round));
+
+        // the end is blocked off, or too far for the rounds in the mapper
+        let no_way = || {
+            let (line, column) = (end.y as usize + 1, end.x as usize + 1);
+            ParseError::new(line, column, "a way through the blizzards")
+        };
+        while let Some((pos, round_taken)) = queue.pop_front() {
+            let cur_wind_state = wind_state_mapper.get(&round_taken).ok_or_else(no_way)?;
+            if !seen.insert((pos, round_taken)) {
+                continue;
+            }
+            let possible_state = get_possible_states(cur_wind_state, &self.wall_state, pos);
+            let found_exit_flag = possible_state.iter().any(|coord| coord.eq(end));
+            match found_exit_flag {
+                true => {
+                    return Ok(round_taken);
+                }
+                false => {
+                    for coord in possible_state {
+                        queue.push_back((coord, round_taken + 1));
+                    }
+                }
+            }
+        }
+        Err(no_way())
+    }
+}
+
+// how long the trips may take before the valley counts as blocked
+const MAX_ROUNDS: i32 = 5000;
+
+fn get_wind_grid(wind_state: &HashSet<(Coord, Direction)>, mx_x: i32, mx_y: i32) -> Grid<bool> {
+    let mut grid_to_return = Grid::new(mx_x as usize + 1, mx_y as usize + 1, false);
+    wind_state.iter().for_each(|(coord, _)| {
+        grid_to_return[(coord.x, coord.y)] = true;
+    });
+
+    grid_to_return
+}
+
+fn get_wind_state_until(
+    initial_wind_state: HashSet<(Coord, Direction)>,
+    mx_x: i32,
+    mx_y: i32,
+    n: i32,
+) -> HashMap<i32, Grid<bool>> {
+    let mut wind_state_mapper = HashMap::new();
+    wind_state_mapper.insert(0, get_wind_grid(&initial_wind_state, mx_x, mx_y));
+    let mut temp_wind_state = initial_wind_state;
+    for index in 1..n {
+        let updated_wind_state = blow(&temp_wind_state, mx_x, mx_y);
+        wind_state_mapper.insert(index, get_wind_grid(&updated_wind_state, mx_x, mx_y));
+        temp_wind_state = updated_wind_state;
+    }
+    log::debug!("wind states: {}", wind_state_mapper.len());
+    wind_state_mapper
+}
+
+// every blizzard one minute later, wrapping around at the walls
+fn blow(
+    wind_state: &HashSet<(Coord, Direction)>,
+    mx_x: i32,
+    mx_y: i32,
+) -> HashSet<(Coord, Direction)> {
+    wind_state
+        .iter()
+        .map(|(coord, direction)| {
+            let mut new_x = coord.x;
+            let mut new_y = coord.y;
+            match direction {
+                Direction::North => {
+                    new_y = coord.y - 1;
+                    if new_y == 0 {
+                        new_y = mx_y - 1;
+                    }
+                }
+                Direction::South => {
+                    new_y = coord.y + 1;
+                    if new_y == mx_y {
+                        new_y = 1;
+                    }
+                }
+                Direction::East => {
+                    new_x = coord.x + 1;
+                    if new_x == mx_x {
+                        new_x = 1;
+                    }
+                }
+                Direction::West => {
+                    new_x = coord.x - 1;
+                    if new_x == 0 {
+                        new_x = mx_x - 1;
+                    }
+                }
+            }
+            (Coord { x: new_x, y: new_y }, *direction)
+        })
+        .collect()
+}
+
+fn get_possible_states(wind_state: &Grid<bool>, wall_state: &Grid<bool>, pos: Coord) -> Vec<Coord> {
+    // update wind_state
+    // 4 case:
+    // 1. x == 0 -> x = mx_x - 1
+    // 2. x == mx_x -> x = 1
+    // 3. y == 0 -> y = mx_y - 1
+    // 4. y == mx_y -> y = 1
+
+    let possible_movements = [(1, 0), (-1, 0), (0, 1), (0, -1), (0, 0)];
+    possible_movements
+        .iter()
+        .map(|(dx, dy)| Coord {
+            x: pos.x + dx,
+            y: pos.y + dy,
+        })
+        .filter(|coord| {
+            let position = (coord.x, coord.y);
+            wall_state.get(position) == Some(&false) && !wind_state[position]
+        })
+        .collect::<Vec<Coord>>()
+}
+
+// the entrance has to be open in the second column of the top row and the
+// exit in the second to last column of the bottom row
+fn read_input(contents: &str) -> Result<Grid<Object>, ParseError> {
+    let grid = Grid::parse(contents, "#, ., ^, v, < or >", |c| match c {
+        '.' => Some(Object::Empty),
+        '#' => Some(Object::Wall),
+        '^' => Some(Object::Wind(Direction::North)),
+        '<' => Some(Object::Wind(Direction::West)),
+        '>' => Some(Object::Wind(Direction::East)),
+        'v' => Some(Object::Wind(Direction::South)),
+        _ => None,
+    })?;
+    let (width, height) = (grid.width(), grid.height());
+    let openings = [
+        ((1, 0), "an entrance"),
+        ((width as i32 - 2, height as i32 - 1), "an exit"),
+    ];
+    for ((x, y), expected) in openings {
+        if height < 2 || !matches!(grid.get((x, y)), Some(Object::Empty)) {
+            let (line, column) = (y.max(0) as usize + 1, x.max(0) as usize + 1);
+            return Err(ParseError::new(line, column, expected));
+        }
+    }
+    Ok(grid)
+}
+
+// rounds taken for each trip: to the exit, back to the start and to the
+// exit again, an error when the blizzards leave no way through
+pub fn run_trips(game: &Game) -> Result<(i32, i32, i32), ParseError> {
+    let mx_x = game.mx_x;
+    let mx_y = game.mx_y;
+    let initial_wind_state = game.wind_state.clone();
+    let wind_state_mapper = get_wind_state_until(initial_wind_state, mx_x, mx_y, MAX_ROUNDS);
+    let round_taken_by_round_one = game.run(
+        &Coord { x: 1, y: 0 },
+        &Coord {
+            x: mx_x - 1,
+            y: mx_y,
+        },
+        &wind_state_mapper,
+        0,
+    )?;
+    log::debug!("round_taken_by_round_one: {}", round_taken_by_round_one);
+    let round_taken_by_round_two = game.run(
+        &Coord {
+            x: game.mx_x - 1,
+            y: game.mx_y,
+        },
+        &Coord { x: 1, y: 0 },
+        &wind_state_mapper,
+        round_taken_by_round_one,
+    )?;
+    log::debug!("round_taken_by_round_two: {}", round_taken_by_round_two);
+    let round_taken_by_round_three = game.run(
+        &Coord { x: 1, y: 0 },
+        &Coord {
+            x: game.mx_x - 1,
+            y: game.mx_y,
+        },
+        &wind_state_mapper,
+        round_taken_by_round_two,
+    )?;
+    log::debug!("round_taken_by_round_three: {}", round_taken_by_round_three);
+
+    Ok((
+        round_taken_by_round_one,
+        round_taken_by_round_two,
+        round_taken_by_round_three,
+    ))
+}
+
+// one frame per minute, with E on every position the expedition could have
+// reached by then. part one makes the first trip, part two all three
+pub fn replay(game: &Game, part: u8, sink: &mut dyn FrameSink) {
+    let start = Coord { x: 1, y: 0 };
+    let exit = Coord {
+        x: game.mx_x - 1,
+        y: game.mx_y,
+    };
+    let trips = if part == 1 {
+        vec![exit]
+    } else {
+        vec![exit, start, exit]
+    };
+
+    let mut wind_state = game.wind_state.clone();
+    let mut reachable = HashSet::from([start]);
+    let mut trip = 0;
+    let mut minute = 0;
+    loop {
+        let goal = trips[trip];
+        if reachable.contains(&goal) {
+            reachable = HashSet::from([goal]);
+            trip += 1;
+        }
+        let done = trip == trips.len() || reachable.is_empty() || minute == MAX_ROUNDS;
+        if sink.wants_frame() || trip == trips.len() || (done && sink.final_only()) {
+            let mut frame = get_frame(game, &wind_state, &reachable);
+            // the position closest to the goal is the one to watch
+            let ahead = reachable
+                .iter()
+                .min_by_key(|coord| (coord.x - goal.x).abs() + (coord.y - goal.y).abs());
+            if let Some(ahead) = ahead {
+                frame.focus_on((ahead.x, ahead.y));
+            }
+            let shown_trip = (trip + 1).min(trips.len());
+            frame.set_caption(format!(
+                "minute {}, trip {} of {}",
+                minute,
+                shown_trip,
+                trips.len()
+            ));
+            sink.frame(&frame);
+        }
+        if done {
+            break;
+        }
+
+        wind_state = blow(&wind_state, game.mx_x, game.mx_y);
+        let wind = get_wind_grid(&wind_state, game.mx_x, game.mx_y);
+        reachable = reachable
+            .iter()
+            .flat_map(|&pos| get_possible_states(&wind, &game.wall_state, pos))
+            .collect();
+        minute += 1;
+    }
+}
+
+// walls, blizzards with a count where several share a tile, and the
+// expedition
+fn get_frame(
+    game: &Game,
+    wind_state: &HashSet<(Coord, Direction)>,
+    reachable: &HashSet<Coord>,
+) -> Frame {
+    let mut frame = Frame::new();
+    for ((x, y), &wall) in game.wall_state.iter() {
+        if wall {
+            frame.set((x, y), '#');
+        }
+    }
+    for (coord, direction) in wind_state {
+        let position = (coord.x, coord.y);
+        let c = match (frame.get(position), direction) {
+            (Some(count), _) if count.is_ascii_digit() => {
+                char::from_digit(count.to_digit(10).unwrap() + 1, 10).unwrap_or('*')
+            }
+            (Some(_), _) => '2',
+            (None, Direction::North) => '^',
+            (None, Direction::South) => 'v',
+            (None, Direction::East) => '>',
+            (None, Direction::West) => '<',
+        };
+        frame.set(position, c);
+    }
+    for coord in reachable {
+        frame.set((coord.x, coord.y), 'E');
+    }
+    frame
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?)?.to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?)?.to_string())
+}
+
+// valley walls and the starting blizzards
+pub fn parse(input: &str) -> Result<Game, ParseError> {
+    Ok(Game::new(&read_input(input)?))
+}
+
+// star 1 answer
+pub fn solve_part1(game: &Game) -> Result<i32, ParseError> {
+    Ok(run_trips(game)?.0)
+}
+
+pub fn solve_part2(game: &Game) -> Result<i32, ParseError> {
+    Ok(run_trips(game)?.2)
+}
+
+const MAX_GENERATED_WIDTH: usize = 120;
+
+// valley size wide, up to the 120 of a real input, and a third of that
+// high, at least four, with a blizzard on a fifth of the tiles. none blow
+// up or down the entrance and exit columns, where they would leave the
+// valley, and valleys the blizzards close off are thrown away. the check
+// and the solver both keep a whole valley for every point in time, which
+// larger sizes would run out of memory on
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.clamp(3, MAX_GENERATED_WIDTH);
+    let height = (width / 3).max(4);
+    let rows = loop {
+        let rows = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|x| match rng.one_in(5) {
+                        false => '.',
+                        true if x == 0 || x == width - 1 => *rng.choose(&['<', '>']),
+                        true => *rng.choose(&['<', '>', '^', 'v']),
+                    })
+                    .collect::<Vec<char>>()
+            })
+            .collect::<Vec<_>>();
+        if has_way_through(&rows) {
+            break rows;
+        }
+    };
+
+    let mut output = format!("#.{}\n", "#".repeat(width));
+    for row in rows {
+        output += &format!("#{}#\n", row.iter().collect::<String>());
+    }
+    output + &format!("{}.#\n", "#".repeat(width))
+}
+
+// whether there is a way from the entrance to the exit and one back. both
+// ends are out of reach of the blizzards, so any start time will do
+fn has_way_through(rows: &[Vec<char>]) -> bool {
+    let (width, height) = (rows[0].len() as i32, rows.len() as i32);
+    let period = (1..).map(|n| n * width).find(|n| n % height == 0).unwrap();
+    let mut occupied = vec![Grid::new(width as usize, height as usize, false); period as usize];
+    for (t, grid) in (0..).zip(occupied.iter_mut()) {
+        for (y, row) in (0i32..).zip(rows) {
+            for (x, blizzard) in (0i32..).zip(row) {
+                let position = match blizzard {
+                    '>' => ((x + t).rem_euclid(width), y),
+                    '<' => ((x - t).rem_euclid(width), y),
+                    'v' => (x, (y + t).rem_euclid(height)),
+                    '^' => (x, (y - t).rem_euclid(height)),
+                    _ => continue,
+                };
+                grid[position] = true;
+            }
+        }
+    }
+
+    let reaches = |from: (i32, i32), to: (i32, i32)| {
+        // the valley at every point in time, from is left out as it is
+        // where the search starts at every time anyway
+        let mut seen = vec![Grid::new(width as usize, height as usize, false); period as usize];
+        let mut queue = (0..period).map(|t| (from, t)).collect::<VecDeque<_>>();
+        while let Some(((x, y), t)) = queue.pop_front() {
+            let next = (t + 1) % period;
+            for (dx, dy) in [(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)] {
+                let position = (x + dx, y + dy);
+                if position == to {
+                    return true;
+                }
+                if occupied[next as usize].get(position) == Some(&false)
+                    && !std::mem::replace(&mut seen[next as usize][position], true)
+                {
+                    queue.push_back((position, next));
+                }
+            }
+        }
+        false
+    };
+    let (entrance, exit) = ((0, -1), (width - 1, height));
+    reaches(entrance, exit) && reaches(exit, entrance)
+}
@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use aoc_common::{ParseError, Rng};
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// one rucksack per line
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut rucksacks = Vec::new();
+    for line in input.lines() {
+        if let Some((index, _)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::at(input, &line[index..], "an item letter"));
+        }
+        rucksacks.push(line.to_string());
+    }
+    Ok(rucksacks)
+}
+
+pub fn solve_part1(rucksacks: &[String]) -> u32 {
+    let mut ans = 0;
+    for line in rucksacks {
+        let (left, right) = line.split_at(line.len() / 2);
+        let set1 = left.chars().collect::<HashSet<char>>();
+        if let Some(c) = right.chars().find(|c| set1.contains(c)) {
+            ans += get_char_score(c);
+        }
+    }
+    ans
+}
+
+pub fn solve_part2(rucksacks: &[String]) -> u32 {
+    let mut ans = 0;
+    let mut set1 = HashSet::<char>::new();
+    let mut set2 = HashSet::<char>::new();
+    for (counter, line) in rucksacks.iter().enumerate() {
+        if counter % 3 == 0 {
+            set1.clear();
+            set2.clear();
+            set1.extend(line.chars());
+        } else if counter % 3 == 1 {
+            for c in line.chars() {
+                if set1.contains(&c) {
+                    set2.insert(c);
+                }
+            }
+        } else if let Some(c) = line.chars().find(|c| set2.contains(c)) {
+            ans += get_char_score(c);
+        }
+    }
+    ans
+}
+
+fn get_char_score(c: char) -> u32 {
+    match c.is_uppercase() {
+        true => c as u32 - 'A' as u32 + 1 + 26,
+        false => c as u32 - 'a' as u32 + 1,
+    }
+}
+
+// size groups of three rucksacks. the halves of a rucksack share a single
+// item and the three rucksacks of a group share only their badge
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let items = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+    let mut output = String::new();
+    for _ in 0..size.max(1) {
+        let mut pool = items.clone();
+        rng.shuffle(&mut pool);
+        let badge = pool.pop().unwrap();
+        // every rucksack of the group fills up from its own third of the rest
+        for own in pool.chunks(pool.len() / 3) {
+            let mut own = own.to_vec();
+            own.push(badge);
+            rng.shuffle(&mut own);
+            let shared = own.pop().unwrap();
+            let (left, right) = own.split_at(rng.index(own.len() + 1));
+
+            let mut halves = [vec![shared], vec![shared]];
+            for (half, only) in halves.iter_mut().zip([left, right]) {
+                if only.contains(&badge) {
+                    half.push(badge);
+                }
+            }
+            let length = halves[0].len().max(halves[1].len()) + rng.index(12);
+            for (half, only) in halves.iter_mut().zip([left, right]) {
+                while half.len() < length {
+                    let item = match only.is_empty() || rng.one_in(4) {
+                        true => shared,
+                        false => *rng.choose(only),
+                    };
+                    half.push(item);
+                }
+                rng.shuffle(half);
+            }
+            output.extend(halves.concat());
+            output.push('\n');
+        }
+    }
+    output
+}
@@ -0,0 +1,93 @@
+use aoc_common::{next_token, parse_token, ParseError, Rng};
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// [[a, b], [c, d]] section ranges for every pair
+pub fn parse(input: &str) -> Result<Vec<Vec<Vec<i32>>>, ParseError> {
+    load_queries(input)
+}
+
+pub fn solve_part1(queries: &[Vec<Vec<i32>>]) -> i32 {
+    let mut ans = 0;
+    for query in queries {
+        if is_bounded(&query[0], &query[1]) {
+            ans += 1;
+        }
+    }
+    ans
+}
+
+pub fn solve_part2(queries: &[Vec<Vec<i32>>]) -> i32 {
+    let mut ans = 0;
+    for query in queries {
+        if is_overlapping(&query[0], &query[1]) {
+            log::trace!(
+                "overlapping: {} {} {} {}",
+                query[0][0],
+                query[0][1],
+                query[1][0],
+                query[1][1]
+            );
+            ans += 1;
+        }
+    }
+    ans
+}
+
+fn load_queries(input: &str) -> Result<Vec<Vec<Vec<i32>>>, ParseError> {
+    let mut queries = Vec::new();
+    for line in input.lines() {
+        let mut pair = line.split(',');
+        let mut query = Vec::new();
+        for _ in 0..2 {
+            let range = next_token(input, line, &mut pair, "a section range")?;
+            let mut bounds = range.split('-');
+            let from = next_token(input, range, &mut bounds, "a section id")?;
+            let to = next_token(input, range, &mut bounds, "a section id")?;
+            query.push(vec![
+                parse_token(input, from, "a section id")?,
+                parse_token(input, to, "a section id")?,
+            ]);
+        }
+        queries.push(query);
+    }
+    Ok(queries)
+}
+
+fn is_bounded(left: &[i32], right: &[i32]) -> bool {
+    let a = left[0];
+    let b = left[1];
+
+    let c = right[0];
+    let d = right[1];
+    (a <= c && b >= d) | (c <= a && d >= b)
+}
+
+fn is_overlapping(left: &[i32], right: &[i32]) -> bool {
+    let a = left[0];
+    let b = left[1];
+
+    let c = right[0];
+    let d = right[1];
+    (b >= c && b <= d) | (a >= c && a <= d) | (d >= a && d <= b) | (c >= a && c <= b)
+}
+
+// size pairs of section ranges within 1 to 99
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..size.max(1) {
+        let mut ranges = Vec::new();
+        for _ in 0..2 {
+            let start = rng.range(1, 99);
+            ranges.push(format!("{}-{}", start, rng.range(start, 99)));
+        }
+        output += &format!("{}\n", ranges.join(","));
+    }
+    output
+}
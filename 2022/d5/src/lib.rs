@@ -0,0 +1,249 @@
+use std::collections::VecDeque;
+
+use aoc_common::{next_token, parse_token, ParseError, Rng};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    from: i32,
+    to: i32,
+    number: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrateHandler {
+    crates: Vec<char>,
+}
+
+impl CrateHandler {
+    fn new() -> CrateHandler {
+        CrateHandler {
+            crates: Vec::<char>::new(),
+        }
+    }
+
+    fn push(&mut self, some_crate: char) {
+        self.crates.push(some_crate);
+    }
+
+    fn pop(&mut self) -> Option<char> {
+        self.crates.pop()
+    }
+
+    fn peek(&self) -> Option<&char> {
+        self.crates.last()
+    }
+}
+
+// starting stacks and the rearrangement procedure
+#[derive(Debug, Clone)]
+pub struct Ship {
+    crate_list: Vec<CrateHandler>,
+    instruction_list: Vec<Instruction>,
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?))
+}
+
+pub fn parse(input: &str) -> Result<Ship, ParseError> {
+    let (first_half, second_half) = split_input(input);
+    let crate_list = get_crate_list(input, &first_half)?;
+    let instruction_list = get_instruction_list(input, &second_half, crate_list.len())?;
+    Ok(Ship {
+        crate_list,
+        instruction_list,
+    })
+}
+
+fn split_input(input: &str) -> (Vec<&str>, Vec<&str>) {
+    // get index on lines where line is empty
+    let mut first_half = Vec::<&str>::new();
+    let mut second_half = Vec::<&str>::new();
+
+    let mut second_half_flag = false;
+    for line in input.lines() {
+        if line.is_empty() {
+            second_half_flag = true;
+            continue;
+        }
+
+        if second_half_flag {
+            second_half.push(line);
+        } else {
+            first_half.push(line);
+        }
+    }
+
+    (first_half, second_half)
+}
+
+pub fn solve_part1(ship: &Ship) -> String {
+    let mut crate_list = ship.crate_list.clone();
+
+    for instruction in ship.instruction_list.iter() {
+        crate_list = execute_instruction(*instruction, crate_list);
+    }
+    get_top_crates(&crate_list)
+}
+
+pub fn solve_part2(ship: &Ship) -> String {
+    let mut crate_list = ship.crate_list.clone();
+
+    for instruction in ship.instruction_list.iter() {
+        crate_list = execute_instruction_2(*instruction, crate_list);
+    }
+    get_top_crates(&crate_list)
+}
+
+// get the crate on the top of each block
+fn get_top_crates(crate_list: &[CrateHandler]) -> String {
+    crate_list.iter().filter_map(|cc| cc.peek()).collect()
+}
+
+// first_half is the drawing of the stacks, ending with the row of stack ids
+pub fn get_crate_list(input: &str, first_half: &[&str]) -> Result<Vec<CrateHandler>, ParseError> {
+    let id_row = match first_half.last() {
+        Some(id_row) => *id_row,
+        None => return Err(ParseError::at(input, input, "a drawing of the stacks")),
+    };
+    let mut crate_list = Vec::<CrateHandler>::new();
+    crate_list.push(CrateHandler::new());
+
+    // ids past 9 take two digits, their crates sit above the first one
+    for id in id_row.split_whitespace() {
+        if let Some((offset, _)) = id.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(ParseError::at(input, &id[offset..], "a stack id"));
+        }
+        let index = id.as_ptr() as usize - id_row.as_ptr() as usize;
+
+        let mut temp = CrateHandler::new();
+        for row in first_half[..first_half.len() - 1].iter().rev() {
+            // rows are only as long as their last crate
+            match row.get(index..).and_then(|rest| rest.chars().next()) {
+                Some(c) if !c.is_whitespace() => temp.push(c),
+                _ => break,
+            }
+        }
+        crate_list.push(temp);
+    }
+    Ok(crate_list)
+}
+
+// stack_count includes the unused stack 0
+pub fn get_instruction_list(
+    input: &str,
+    second_half: &[&str],
+    stack_count: usize,
+) -> Result<Vec<Instruction>, ParseError> {
+    let mut instruction_list = Vec::<Instruction>::new();
+    for line in second_half {
+        let mut iter = line.split_whitespace();
+        let mut fields = [0; 3];
+        for (field, keyword) in fields.iter_mut().zip(["move", "from", "to"]) {
+            let word = next_token(input, line, &mut iter, format!("'{}'", keyword))?;
+            if word != keyword {
+                return Err(ParseError::at(input, word, format!("'{}'", keyword)));
+            }
+            let token = next_token(input, line, &mut iter, "a number")?;
+            *field = parse_token::<i32>(input, token, "a number")?;
+            if keyword != "move" && !(1..stack_count as i32).contains(field) {
+                return Err(ParseError::at(input, token, "an existing stack id"));
+            }
+        }
+        let [number, from, to] = fields;
+        instruction_list.push(Instruction { from, to, number });
+    }
+
+    log::trace!("instructions: {:?}", instruction_list);
+    Ok(instruction_list)
+}
+
+fn execute_instruction_2(
+    instruction: Instruction,
+    mut crate_list: Vec<CrateHandler>,
+) -> Vec<CrateHandler> {
+    let Instruction { from, to, number } = instruction;
+
+    let mut temp = VecDeque::<char>::new();
+    for _i in 0..number {
+        temp.push_front(crate_list[from as usize].pop().unwrap());
+    }
+
+    for _i in 0..number {
+        crate_list[to as usize].push(temp.pop_front().unwrap());
+    }
+
+    crate_list
+}
+
+fn execute_instruction(
+    instruction: Instruction,
+    mut crate_list: Vec<CrateHandler>,
+) -> Vec<CrateHandler> {
+    let Instruction { from, to, number } = instruction;
+
+    let mut temp = Vec::<char>::new();
+    for i in 0..number {
+        temp.push(crate_list[from as usize].pop().unwrap());
+        crate_list[to as usize].push(temp[i as usize]);
+    }
+
+    crate_list
+}
+
+// size stacks, so ids past 9 take two digits, and twice as many moves. a
+// move only takes crates that are on the stack it takes them from
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(2);
+    let mut stacks = Vec::new();
+    for _ in 0..count {
+        let height = rng.range(0, 8);
+        let stack = (0..height)
+            .map(|_| (b'A' + rng.index(26) as u8) as char)
+            .collect::<Vec<char>>();
+        stacks.push(stack);
+    }
+    if stacks.iter().all(Vec::is_empty) {
+        stacks[0].push('A');
+    }
+
+    let mut output = String::new();
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    for level in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        output += row.trim_end();
+        output.push('\n');
+    }
+    let ids = (1..=count)
+        .map(|id| format!(" {:<3}", id))
+        .collect::<String>();
+    output += ids.trim_end();
+    output += "\n\n";
+
+    for _ in 0..count * 2 {
+        let from = loop {
+            let from = rng.index(count);
+            if !stacks[from].is_empty() {
+                break from;
+            }
+        };
+        let to = (from + 1 + rng.index(count - 1)) % count;
+        let height = stacks[from].len();
+        let number = rng.range(1, height as i64) as usize;
+        let moved = stacks[from].split_off(height - number);
+        stacks[to].extend(moved);
+        output += &format!("move {} from {} to {}\n", number, from + 1, to + 1);
+    }
+    output
+}
@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use aoc_common::{ParseError, Rng};
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?)?.to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?)?.to_string())
+}
+
+// datastream buffer as a list of characters
+pub fn parse(input: &str) -> Result<Vec<char>, ParseError> {
+    load_char_list(input)
+}
+
+// start-of-packet marker, an error when the stream has none
+pub fn solve_part1(char_list: &[char]) -> Result<usize, ParseError> {
+    solution_1(char_list, 4)
+}
+
+// start-of-message marker, an error when the stream has none
+pub fn solve_part2(char_list: &[char]) -> Result<usize, ParseError> {
+    solution_1(char_list, 14)
+}
+
+fn load_char_list(input: &str) -> Result<Vec<char>, ParseError> {
+    let mut char_list = Vec::new();
+    for line in input.lines() {
+        for (index, c) in line.char_indices() {
+            if !c.is_ascii_lowercase() {
+                return Err(ParseError::at(input, &line[index..], "a lowercase letter"));
+            }
+            char_list.push(c);
+        }
+    }
+    Ok(char_list)
+}
+
+// position just after the first n different characters in a row, an error
+// pointing past the end of the stream when there are none
+pub fn solution_1(char_list: &[char], n: usize) -> Result<usize, ParseError> {
+    let mut sliding_window = HashSet::<char>::new();
+    let mut start = 0;
+
+    for end in 0..char_list.len() {
+        // move start to the index+1 of the first duplicate
+        while sliding_window.contains(&char_list[end]) {
+            sliding_window.remove(&char_list[start]);
+            start += 1;
+        }
+
+        sliding_window.insert(char_list[end]);
+        if end - start + 1 == n {
+            return Ok(end + 1);
+        }
+    }
+
+    Err(ParseError::new(
+        1,
+        char_list.len() + 1,
+        format!("a marker of {} different characters", n),
+    ))
+}
+
+// a stream with size characters before its first start-of-message marker
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // thirteen letters are too few for a start-of-message marker
+    let mut stream = (0..size)
+        .map(|_| (b'a' + rng.index(13) as u8) as char)
+        .collect::<String>();
+    let mut letters = ('a'..='z').collect::<Vec<char>>();
+    rng.shuffle(&mut letters);
+    stream.extend(&letters[..14]);
+    stream.extend((0..rng.index(size + 1)).map(|_| (b'a' + rng.index(26) as u8) as char));
+    stream + "\n"
+}
@@ -0,0 +1,95 @@
+use std::{collections::HashMap, fmt::Write};
+
+use petgraph::graph::NodeIndex;
+use serde::Serialize;
+
+use crate::{format_size, node_sizes, report::sorted_children, NodeGraph, ReportOptions};
+
+// an entry of the filesystem with everything below it, directories sized by
+// their contents. files have no children but keep the empty list so that
+// every entry has the same shape
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Entry {
+    pub name: String,
+    pub size: u64,
+    pub is_directory: bool,
+    pub children: Vec<Entry>,
+}
+
+// the filesystem as nested entries, cut at max_depth like the tree
+pub fn entries(graph: &NodeGraph, options: &ReportOptions) -> Entry {
+    let sizes = node_sizes(graph);
+    entry(graph, &sizes, graph.get_root(), 0, options)
+}
+
+fn entry(
+    graph: &NodeGraph,
+    sizes: &HashMap<NodeIndex, u64>,
+    node: NodeIndex,
+    depth: usize,
+    options: &ReportOptions,
+) -> Entry {
+    let node_data = &graph.get_graph()[node];
+    let children = if options.max_depth.is_none_or(|max_depth| depth < max_depth) {
+        sorted_children(graph, sizes, node, options.sort)
+            .into_iter()
+            .map(|child| entry(graph, sizes, child, depth + 1, options))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    Entry {
+        name: node_data.name.clone(),
+        size: sizes[&node],
+        is_directory: node_data.is_directory,
+        children,
+    }
+}
+
+// the nested entries as pretty printed json
+pub fn json(graph: &NodeGraph, options: &ReportOptions) -> String {
+    let mut output =
+        serde_json::to_string_pretty(&entries(graph, options)).expect("entries are plain data");
+    output.push('\n');
+    output
+}
+
+// the filesystem as a graphviz digraph, directories as boxes labelled with
+// their name and the size of their contents, files as plain text
+//
+//     dot -Tsvg filesystem.dot > filesystem.svg
+pub fn dot(graph: &NodeGraph, options: &ReportOptions) -> String {
+    let sizes = node_sizes(graph);
+    let mut output = String::from("digraph filesystem {\n    rankdir=LR;\n");
+    let mut stack = vec![(graph.get_root(), 0)];
+    while let Some((node, depth)) = stack.pop() {
+        let entry = &graph.get_graph()[node];
+        let shape = if entry.is_directory {
+            "box"
+        } else {
+            "plaintext"
+        };
+        let _ = writeln!(
+            output,
+            "    n{} [label=\"{}\\n{}\", shape={}];",
+            node.index(),
+            escape(&entry.name),
+            format_size(sizes[&node], options.human),
+            shape
+        );
+        if options.max_depth.is_none_or(|max_depth| depth < max_depth) {
+            let children = sorted_children(graph, &sizes, node, options.sort);
+            for child in &children {
+                let _ = writeln!(output, "    n{} -> n{};", node.index(), child.index());
+            }
+            stack.extend(children.into_iter().rev().map(|child| (child, depth + 1)));
+        }
+    }
+    output.push_str("}\n");
+    output
+}
+
+// names are free text, so quotes and backslashes would end the label early
+fn escape(name: &str) -> String {
+    name.replace('\\', "\\\\").replace('"', "\\\"")
+}
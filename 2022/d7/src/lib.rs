@@ -0,0 +1,368 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    error::Error,
+};
+
+use aoc_common::{next_token, parse_token, ParseError, Rng};
+use petgraph::{graph::NodeIndex, visit::EdgeRef, Graph};
+use serde::{Deserialize, Serialize};
+
+mod export;
+mod plan;
+mod report;
+
+pub use export::{dot, entries, json, Entry};
+pub use plan::{plan, plan_report, Deletion, Plan};
+pub use report::{du, format_size, tree, ReportOptions, SortBy};
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    size: u32,
+    is_directory: bool,
+    parent_node: Option<NodeIndex>,
+}
+
+pub struct NodeGraph {
+    graph: Graph<Node, ()>,
+    root: NodeIndex,
+    current_node: NodeIndex,
+}
+
+impl NodeGraph {
+    fn new() -> Self {
+        let mut graph = Graph::<Node, ()>::new();
+        let root: NodeIndex = graph.add_node(Node {
+            name: "/".to_string(),
+            size: 0,
+            is_directory: true,
+            parent_node: None,
+        });
+        NodeGraph {
+            graph,
+            root,
+            current_node: root,
+        }
+    }
+
+    // adds an entry listed in the current directory. listing a directory
+    // again only updates the sizes of its files, false when an entry changed
+    // from file to directory or back
+    fn add_node(&mut self, name: &str, is_directory: bool, size: Option<u32>) -> bool {
+        if let Some(existing) = self.find_child(self.current_node, name) {
+            let node = &mut self.graph[existing];
+            if node.is_directory != is_directory {
+                return false;
+            }
+            node.size = size.unwrap_or(0);
+            return true;
+        }
+        let node = Node {
+            name: name.to_string(),
+            size: size.unwrap_or(0),
+            is_directory,
+            parent_node: Some(self.current_node),
+        };
+        let new_node_index = self.graph.add_node(node);
+        self.graph.add_edge(self.current_node, new_node_index, ());
+        true
+    }
+
+    fn find_child(&self, node: NodeIndex, wanted_name: &str) -> Option<NodeIndex> {
+        self.graph
+            .edges(node)
+            .map(|edge| edge.target())
+            .find(|&child| self.graph[child].name == wanted_name)
+    }
+
+    // follows path from the current directory, or from the root when it
+    // starts with /, the way cd does. .. at the root stays there. on error
+    // nothing changes, and the segment that is not a directory is returned
+    // along with the directory it was looked for in
+    fn change_directory<'a>(&mut self, path: &'a str) -> Result<(), (&'a str, NodeIndex)> {
+        let mut node = if path.starts_with('/') {
+            self.root
+        } else {
+            self.current_node
+        };
+        for segment in path.split('/') {
+            node = match segment {
+                "" | "." => node,
+                ".." => self.graph[node].parent_node.unwrap_or(node),
+                name => match self.find_child(node, name) {
+                    Some(child) if self.graph[child].is_directory => child,
+                    _ => return Err((segment, node)),
+                },
+            };
+        }
+        self.current_node = node;
+        Ok(())
+    }
+
+    // path of node from the root, such as /a/e
+    fn path_of(&self, node: NodeIndex) -> String {
+        let mut names = Vec::new();
+        let mut current = node;
+        while let Some(parent) = self.graph[current].parent_node {
+            names.push(self.graph[current].name.as_str());
+            current = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    pub fn directory_sizes(&self) -> DirectorySizes {
+        DirectorySizes::new(self)
+    }
+
+    fn get_root(&self) -> NodeIndex {
+        self.root
+    }
+
+    fn get_graph(&self) -> &Graph<Node, ()> {
+        &self.graph
+    }
+}
+
+// size of every directory along with everything below it, kept sorted by
+// size for the queries
+pub struct DirectorySizes {
+    directories: Vec<(u64, String)>,
+}
+
+impl DirectorySizes {
+    fn new(graph: &NodeGraph) -> DirectorySizes {
+        let mut directories = node_sizes(graph)
+            .into_iter()
+            .filter(|(node, _)| graph.get_graph()[*node].is_directory)
+            .map(|(node, size)| (size, graph.path_of(node)))
+            .collect::<Vec<_>>();
+        directories.sort();
+        DirectorySizes { directories }
+    }
+
+    // size of the root, everything in use
+    pub fn total(&self) -> u64 {
+        self.directories.last().map_or(0, |(size, _)| *size)
+    }
+
+    // sizes added up over the directories of at most limit, counting the
+    // files of nested ones again
+    pub fn sum_at_most(&self, limit: u64) -> u64 {
+        self.directories
+            .iter()
+            .take_while(|(size, _)| *size <= limit)
+            .map(|(size, _)| size)
+            .sum()
+    }
+
+    pub fn smallest_at_least(&self, size: u64) -> Option<u64> {
+        self.smallest_directory_at_least(size)
+            .map(|(size, _)| *size)
+    }
+
+    // size and path of the smallest directory of at least size
+    pub fn smallest_directory_at_least(&self, size: u64) -> Option<&(u64, String)> {
+        let index = self.directories.partition_point(|(other, _)| *other < size);
+        self.directories.get(index)
+    }
+
+    // paths and sizes of the k largest directories, largest first
+    pub fn largest(&self, k: usize) -> Vec<(String, u64)> {
+        self.directories
+            .iter()
+            .rev()
+            .take(k)
+            .map(|(size, path)| (path.clone(), *size))
+            .collect()
+    }
+}
+
+// size of every file and of every directory with everything below it, in a
+// single pass over the graph
+fn node_sizes(graph: &NodeGraph) -> HashMap<NodeIndex, u64> {
+    fn visit(graph: &NodeGraph, node: NodeIndex, sizes: &mut HashMap<NodeIndex, u64>) -> u64 {
+        let current_node = &graph.get_graph()[node];
+        let mut total_weight = u64::from(current_node.size);
+        for edge in graph.get_graph().edges(node) {
+            total_weight += visit(graph, edge.target(), sizes);
+        }
+        log::trace!("{} {}", current_node.name, total_weight);
+        sizes.insert(node, total_weight);
+        total_weight
+    }
+
+    let mut sizes = HashMap::new();
+    visit(graph, graph.get_root(), &mut sizes);
+    sizes
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+// an error when not even the root frees up enough space
+pub fn part2(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(solve_part2(&parse(input)?)?.to_string())
+}
+
+// filesystem rebuilt from the terminal transcript
+pub fn parse(input: &str) -> Result<NodeGraph, ParseError> {
+    load_graph(input)
+}
+
+// directories part one counts are at most small_size, part two frees up
+// required_space on a disk of disk_size
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub small_size: u64,
+    pub disk_size: u64,
+    pub required_space: u64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            small_size: 100000,
+            disk_size: 70000000,
+            required_space: 30000000,
+        }
+    }
+}
+
+pub fn solve_part1(graph: &NodeGraph) -> u64 {
+    solve_part1_with(graph, &Params::default())
+}
+
+pub fn solve_part2(graph: &NodeGraph) -> Result<u64, String> {
+    solve_part2_with(graph, &Params::default())
+}
+
+// sum of all directories of at most small_size
+pub fn solve_part1_with(graph: &NodeGraph, params: &Params) -> u64 {
+    graph.directory_sizes().sum_at_most(params.small_size)
+}
+
+// size of the smallest directory that frees up enough space, an error when
+// not even the root is enough for the space required
+pub fn solve_part2_with(graph: &NodeGraph, params: &Params) -> Result<u64, String> {
+    let sizes = graph.directory_sizes();
+    let amt_to_free = (params.required_space + sizes.total()).saturating_sub(params.disk_size);
+    log::debug!("total size: {} amt to free: {}", sizes.total(), amt_to_free);
+    sizes.smallest_at_least(amt_to_free).ok_or_else(|| {
+        format!(
+            "no directory frees up {} of the disk of {}",
+            amt_to_free, params.disk_size
+        )
+    })
+}
+
+// replays the transcript of a shell session, cd and ls with their output.
+// the session starts at the root, so a leading cd / is not needed
+pub fn load_graph(input: &str) -> Result<NodeGraph, ParseError> {
+    let mut graph = NodeGraph::new();
+    // listings are only expected right after an ls
+    let mut listing = false;
+    for line in input.lines() {
+        log::trace!("{}", line);
+        let mut splitted = line.split_whitespace();
+
+        let first = next_token(input, line, &mut splitted, "a command or a listing")?;
+        if first == "$" {
+            // command either cd or ls
+            let command = next_token(input, line, &mut splitted, "cd or ls")?;
+            listing = false;
+            match command {
+                "cd" => {
+                    let path = next_token(input, line, &mut splitted, "a directory")?;
+                    if let Err((segment, node)) = graph.change_directory(path) {
+                        let expected = format!("a directory of {}", graph.path_of(node));
+                        return Err(ParseError::at(input, segment, expected));
+                    }
+                }
+                "ls" => listing = true,
+                _ => return Err(ParseError::at(input, command, "cd or ls")),
+            }
+            continue;
+        }
+        if !listing {
+            return Err(ParseError::at(input, first, "a command such as $ ls"));
+        }
+
+        // names run to the end of the line and may hold spaces
+        let rest = line.trim_start()[first.len()..].trim();
+        if rest.is_empty() {
+            return Err(ParseError::at(input, &line[line.len()..], "a name"));
+        }
+        let added = match first {
+            // dir, dirname
+            "dir" => graph.add_node(rest, true, None),
+            // size, filename
+            size => {
+                let file_size = parse_token::<u32>(input, size, "a file size or dir")?;
+                graph.add_node(rest, false, Some(file_size))
+            }
+        };
+        if !added {
+            return Err(ParseError::at(
+                input,
+                rest,
+                "the same kind of entry as before",
+            ));
+        }
+    }
+    Ok(graph)
+}
+
+// transcript of listing a random tree of size files and directories
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut output = String::from("$ cd /\n");
+    let mut remaining = size.max(1);
+    generate_directory(rng, &mut remaining, 0, &mut output);
+
+    // part two needs more than 40000000 in use, make up the difference
+    // with one big file in /
+    let total = output
+        .lines()
+        .filter_map(|line| line.split_whitespace().next()?.parse::<u32>().ok())
+        .sum::<u32>();
+    let needed = 40_000_001u32.saturating_sub(total);
+    if needed > 0 {
+        let file = format!("{} disk.bin\n", needed + rng.range(0, 1_000_000) as u32);
+        output.insert_str("$ cd /\n$ ls\n".len(), &file);
+    }
+    output
+}
+
+fn generate_directory(rng: &mut Rng, remaining: &mut usize, depth: usize, output: &mut String) {
+    output.push_str("$ ls\n");
+    let mut names = BTreeSet::new();
+    let mut directories = Vec::new();
+    let entries = (rng.range(1, 6) as usize).min(*remaining);
+    *remaining -= entries;
+    for _ in 0..entries {
+        let name = loop {
+            let mut name = (0..rng.range(1, 8))
+                .map(|_| (b'a' + rng.index(26) as u8) as char)
+                .collect::<String>();
+            if rng.one_in(2) {
+                name += *rng.choose(&[".txt", ".dat", ".log", ".lst"]);
+            }
+            if names.insert(name.clone()) {
+                break name;
+            }
+        };
+        if depth < 8 && rng.one_in(3) {
+            output.push_str(&format!("dir {}\n", name));
+            directories.push(name);
+        } else {
+            output.push_str(&format!("{} {}\n", rng.range(1, 300000), name));
+        }
+    }
+    for name in directories {
+        output.push_str(&format!("$ cd {}\n", name));
+        generate_directory(rng, remaining, depth + 1, output);
+        output.push_str("$ cd ..\n");
+    }
+}
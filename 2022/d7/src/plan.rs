@@ -0,0 +1,253 @@
+use std::{collections::HashMap, fmt::Write};
+
+use petgraph::{graph::NodeIndex, visit::EdgeRef};
+
+use crate::{format_size, node_sizes, NodeGraph, Params};
+
+// branches looked at before the search for the smallest set gives up. it
+// takes time exponential in the number of directories when no set frees
+// exactly what is needed, which is under a second for this many
+const SEARCH_LIMIT: u64 = 10_000_000;
+
+// a directory to delete along with everything below it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deletion {
+    pub path: String,
+    pub size: u64,
+}
+
+// what to delete so that required_space is free on a disk of disk_size,
+// either a single directory like part two or a set of them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub capacity: u64,
+    pub used: u64,
+    pub required: u64,
+    // space still to free, none when enough is free already
+    pub needed: u64,
+    // the smallest directory that frees enough on its own
+    pub smallest: Option<Deletion>,
+    // directories none of which holds another that free enough together
+    // while freeing as little as possible
+    pub set: Option<Vec<Deletion>>,
+    // whether no smaller set can exist, the search gives up on many
+    // directories and keeps the best set it came across
+    pub proven: bool,
+}
+
+impl Plan {
+    pub fn free(&self) -> u64 {
+        self.capacity.saturating_sub(self.used)
+    }
+
+    // free space once deletions are gone
+    pub fn free_after(&self, deletions: &[Deletion]) -> u64 {
+        let freed = deletions.iter().map(|deletion| deletion.size).sum::<u64>();
+        self.capacity.saturating_sub(self.used - freed)
+    }
+}
+
+pub fn plan(graph: &NodeGraph, params: &Params) -> Plan {
+    let sizes = graph.directory_sizes();
+    let used = sizes.total();
+    let needed = (params.required_space + used).saturating_sub(params.disk_size);
+    let mut plan = Plan {
+        capacity: params.disk_size,
+        used,
+        required: params.required_space,
+        needed,
+        smallest: None,
+        set: None,
+        proven: true,
+    };
+    if needed == 0 {
+        plan.set = Some(Vec::new());
+        return plan;
+    }
+    // nothing frees more than the root, so when even that is not enough
+    // there is no plan
+    let smallest = match sizes.smallest_directory_at_least(needed) {
+        Some((size, path)) => Deletion {
+            path: path.clone(),
+            size: *size,
+        },
+        None => return plan,
+    };
+
+    let node_sizes = node_sizes(graph);
+    let search = Search::new(graph, &node_sizes);
+    let (found, proven) = search.smallest_set(needed, smallest.size);
+    let mut set = found
+        .into_iter()
+        .map(|node| Deletion {
+            path: graph.path_of(node),
+            size: node_sizes[&node],
+        })
+        .collect::<Vec<_>>();
+    if set.is_empty() {
+        set.push(smallest.clone());
+    }
+    set.sort_by(|a, b| a.path.cmp(&b.path));
+    plan.smallest = Some(smallest);
+    plan.set = Some(set);
+    plan.proven = proven;
+    plan
+}
+
+// the smallest set is a subset sum over the tree. going through the
+// directories in order, each one is either deleted whole, which adds its
+// size and skips what is below it, or kept, going on with its
+// subdirectories. branches that cannot free enough any more or free no
+// less than the best set so far are cut, so the work depends on the shape
+// of the tree and not on how big the files are
+struct Search {
+    // directories in order, bigger ones first among siblings
+    nodes: Vec<NodeIndex>,
+    sizes: Vec<u64>,
+    // where the directories below each one end
+    ends: Vec<usize>,
+    // the most that directories from each one on free without overlapping
+    most: Vec<u64>,
+}
+
+impl Search {
+    fn new(graph: &NodeGraph, sizes: &HashMap<NodeIndex, u64>) -> Search {
+        let mut search = Search {
+            nodes: Vec::new(),
+            sizes: Vec::new(),
+            ends: Vec::new(),
+            most: Vec::new(),
+        };
+        search.visit(graph, sizes, graph.get_root());
+        search.most = vec![0; search.nodes.len() + 1];
+        for index in (0..search.nodes.len()).rev() {
+            search.most[index] = search.sizes[index] + search.most[search.ends[index]];
+        }
+        search
+    }
+
+    fn visit(&mut self, graph: &NodeGraph, sizes: &HashMap<NodeIndex, u64>, node: NodeIndex) {
+        let index = self.nodes.len();
+        self.nodes.push(node);
+        self.sizes.push(sizes[&node]);
+        self.ends.push(index + 1);
+        let mut children = graph
+            .get_graph()
+            .edges(node)
+            .map(|edge| edge.target())
+            .filter(|child| graph.get_graph()[*child].is_directory)
+            .collect::<Vec<_>>();
+        children.sort_by_key(|child| std::cmp::Reverse(sizes[child]));
+        for child in children {
+            self.visit(graph, sizes, child);
+        }
+        self.ends[index] = self.nodes.len();
+    }
+
+    // directories freeing at least needed and less than best, or nothing
+    // when there are none, and whether all branches were looked at. the
+    // branches are on a stack rather than recursive calls as a long enough
+    // row of kept directories would run out of stack
+    fn smallest_set(&self, needed: u64, mut best: u64) -> (Vec<NodeIndex>, bool) {
+        let count = self.nodes.len();
+        let mut looked_at = 0;
+        let mut proven = true;
+        let mut found = Vec::new();
+        // the directories deleted on the way to each branch are the first
+        // ones of deleted, with the one the branch deletes last
+        let mut deleted = Vec::new();
+        let mut branches = vec![(0, 0, 0, None)];
+        while let Some((index, freed, length, deleting)) = branches.pop() {
+            if best == needed {
+                break;
+            }
+            if looked_at == SEARCH_LIMIT {
+                proven = false;
+                break;
+            }
+            looked_at += 1;
+            deleted.truncate(length);
+            deleted.extend(deleting);
+            if index == count || freed + self.most[index] < needed {
+                continue;
+            }
+            // deleting goes first, so it is pushed last
+            branches.push((index + 1, freed, deleted.len(), None));
+            let freed = freed + self.sizes[index];
+            if freed >= best {
+                continue;
+            }
+            if freed >= needed {
+                best = freed;
+                found.clone_from(&deleted);
+                found.push(index);
+            } else {
+                branches.push((self.ends[index], freed, deleted.len(), Some(index)));
+            }
+        }
+        let found = found.into_iter().map(|index| self.nodes[index]).collect();
+        (found, proven)
+    }
+}
+
+// the plan in words, sizes in K, M and G with human
+pub fn plan_report(plan: &Plan, human: bool) -> String {
+    let size = |size| format_size(size, human);
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "capacity {}, used {}, free {}, required {}",
+        size(plan.capacity),
+        size(plan.used),
+        size(plan.free()),
+        size(plan.required)
+    );
+    if plan.needed == 0 {
+        output.push_str("enough space is free already, nothing to delete\n");
+        return output;
+    }
+    let _ = writeln!(output, "to free {}", size(plan.needed));
+    match &plan.smallest {
+        Some(deletion) => {
+            let _ = writeln!(
+                output,
+                "smallest directory: {} ({}), leaving {} free",
+                deletion.path,
+                size(deletion.size),
+                size(plan.free_after(std::slice::from_ref(deletion)))
+            );
+        }
+        None => output.push_str("no directory frees up enough space\n"),
+    }
+    if let Some(set) = &plan.set {
+        let freed = set.iter().map(|deletion| deletion.size).sum::<u64>();
+        let _ = writeln!(
+            output,
+            "{}: {} {}, freeing {} and leaving {} free",
+            if plan.proven {
+                "smallest set"
+            } else {
+                "best set found"
+            },
+            set.len(),
+            if set.len() == 1 {
+                "directory"
+            } else {
+                "directories"
+            },
+            size(freed),
+            size(plan.free_after(set))
+        );
+        for deletion in set {
+            let _ = writeln!(output, "  {}\t{}", size(deletion.size), deletion.path);
+        }
+        if !plan.proven {
+            let _ = writeln!(
+                output,
+                "gave up after {} branches, a smaller set may exist",
+                SEARCH_LIMIT
+            );
+        }
+    }
+    output
+}
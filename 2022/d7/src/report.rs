@@ -0,0 +1,121 @@
+use std::{cmp::Reverse, collections::HashMap, fmt::Write};
+
+use petgraph::{graph::NodeIndex, visit::EdgeRef};
+
+use crate::{node_sizes, NodeGraph};
+
+// order of the entries of a directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortBy {
+    #[default]
+    Name,
+    // largest first
+    Size,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReportOptions {
+    pub sort: SortBy,
+    // levels shown below the root, all of them without it
+    pub max_depth: Option<usize>,
+    // sizes in K, M and G rather than in bytes
+    pub human: bool,
+}
+
+// the filesystem the way the puzzle draws it, with the size of everything
+// below every directory
+//
+//     - / (dir, size=48381165)
+//       - a (dir, size=94853)
+//         - e (dir, size=584)
+//           - i (file, size=584)
+pub fn tree(graph: &NodeGraph, options: &ReportOptions) -> String {
+    let sizes = node_sizes(graph);
+    let mut output = String::new();
+    let mut stack = vec![(graph.get_root(), 0)];
+    while let Some((node, depth)) = stack.pop() {
+        let entry = &graph.get_graph()[node];
+        let kind = if entry.is_directory { "dir" } else { "file" };
+        let _ = writeln!(
+            output,
+            "{}- {} ({}, size={})",
+            "  ".repeat(depth),
+            entry.name,
+            kind,
+            format_size(sizes[&node], options.human)
+        );
+        if options.max_depth.is_none_or(|max_depth| depth < max_depth) {
+            // pushed backwards so that they come off the stack in order
+            let children = sorted_children(graph, &sizes, node, options.sort);
+            stack.extend(children.into_iter().rev().map(|child| (child, depth + 1)));
+        }
+    }
+    output
+}
+
+// size and full path of every directory, one per line and separated by a
+// tab like du prints them
+pub fn du(graph: &NodeGraph, options: &ReportOptions) -> String {
+    let sizes = node_sizes(graph);
+    let mut directories = Vec::new();
+    let mut stack = vec![(graph.get_root(), 0)];
+    while let Some((node, depth)) = stack.pop() {
+        if !graph.get_graph()[node].is_directory {
+            continue;
+        }
+        if options.max_depth.is_none_or(|max_depth| depth <= max_depth) {
+            directories.push((graph.path_of(node), sizes[&node]));
+        }
+        stack.extend(
+            graph
+                .get_graph()
+                .edges(node)
+                .map(|edge| (edge.target(), depth + 1)),
+        );
+    }
+    match options.sort {
+        SortBy::Name => directories.sort(),
+        SortBy::Size => directories.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0))),
+    }
+
+    let mut output = String::new();
+    for (path, size) in directories {
+        let _ = writeln!(output, "{}\t{}", format_size(size, options.human), path);
+    }
+    output
+}
+
+pub(crate) fn sorted_children(
+    graph: &NodeGraph,
+    sizes: &HashMap<NodeIndex, u64>,
+    node: NodeIndex,
+    sort: SortBy,
+) -> Vec<NodeIndex> {
+    let mut children = graph
+        .get_graph()
+        .edges(node)
+        .map(|edge| edge.target())
+        .collect::<Vec<_>>();
+    let name = |child: &NodeIndex| graph.get_graph()[*child].name.clone();
+    match sort {
+        SortBy::Name => children.sort_by_key(name),
+        SortBy::Size => children.sort_by_key(|child| (Reverse(sizes[child]), name(child))),
+    }
+    children
+}
+
+// bytes as they are, or in the largest unit of 1024 that keeps them above
+// one, such as 46.1M
+pub fn format_size(size: u64, human: bool) -> String {
+    if !human || size < 1024 {
+        return size.to_string();
+    }
+    let units = ["K", "M", "G", "T", "P"];
+    let mut value = size as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < units.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1}{}", value, units[unit])
+}
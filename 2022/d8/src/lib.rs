@@ -0,0 +1,168 @@
+use aoc_common::{Frame, FrameSink, Grid, ParseError, Rng};
+use rayon::prelude::*;
+
+#[derive(Debug, Clone, Copy)]
+struct Tree {
+    height: i32,
+    seen: bool,
+}
+
+#[derive(Clone)]
+pub struct Forest {
+    tree_matrix: Grid<Tree>,
+}
+
+impl Forest {
+    fn get_total_seen(&self) -> i32 {
+        self.tree_matrix
+            .iter()
+            .filter(|(_, tree)| tree.seen)
+            .count() as i32
+    }
+
+    fn update_tree_seen(&mut self) {
+        let n = self.tree_matrix.height() as i32;
+        let m = self.tree_matrix.width() as i32;
+
+        // walk in from every tree on the edge, from top, bottom, left and right
+        let mut edges = Vec::new();
+        for x in 0..m {
+            edges.push(((x, 0), (0, 1)));
+            edges.push(((x, n - 1), (0, -1)));
+        }
+        for y in 0..n {
+            edges.push(((0, y), (1, 0)));
+            edges.push(((m - 1, y), (-1, 0)));
+        }
+
+        for (mut position, (dx, dy)) in edges {
+            let mut lo = -1;
+            while let Some(tree) = self.tree_matrix.get_mut(position) {
+                if tree.height > lo {
+                    tree.seen = true;
+                    lo = tree.height;
+                }
+                position = (position.0 + dx, position.1 + dy);
+            }
+        }
+    }
+
+    fn get_scenic_score(&self, position: (i32, i32)) -> i32 {
+        [(0, -1), (0, 1), (-1, 0), (1, 0)]
+            .into_iter()
+            .map(|step| self.get_trees_in_view(position, step))
+            .product()
+    }
+
+    // trees seen walking away from position, up to and including the first
+    // one at least as tall
+    fn get_trees_in_view(&self, (x, y): (i32, i32), (dx, dy): (i32, i32)) -> i32 {
+        let original_height = self.tree_matrix[(x, y)].height;
+        let mut no_of_trees_in_view = 0;
+        let mut position = (x + dx, y + dy);
+        while let Some(tree) = self.tree_matrix.get(position) {
+            no_of_trees_in_view += 1;
+            if tree.height >= original_height {
+                break;
+            }
+            position = (position.0 + dx, position.1 + dy);
+        }
+        no_of_trees_in_view
+    }
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// tree heights, row by row
+pub fn parse(input: &str) -> Result<Forest, ParseError> {
+    load_forest(input)
+}
+
+// number of trees visible from outside the grid
+pub fn solve_part1(forest: &Forest) -> i32 {
+    let mut forest = forest.clone();
+    forest.update_tree_seen();
+    forest.get_total_seen()
+}
+
+// highest scenic score of any tree
+pub fn solve_part2(forest: &Forest) -> i32 {
+    solution_2(forest)
+}
+
+pub fn load_forest(input: &str) -> Result<Forest, ParseError> {
+    let tree_matrix = Grid::parse(input, "a tree height", |c| {
+        c.to_digit(10).map(|height| Tree {
+            height: height as i32,
+            seen: false,
+        })
+    })?;
+    Ok(Forest { tree_matrix })
+}
+
+pub fn solution_2(forest: &Forest) -> i32 {
+    let ((x, y), max_score) = best_view(forest);
+    log::debug!("x:{}, y:{} ans:{}", x, y, max_score);
+    max_score
+}
+
+// tree with the highest scenic score, and the score. the scores are worked
+// out in parallel when there is a thread pool, the first tree in reading
+// order wins a tie
+fn best_view(forest: &Forest) -> ((i32, i32), i32) {
+    let positions = forest.tree_matrix.positions().collect::<Vec<_>>();
+    let scores = aoc_common::parallel(|| {
+        positions
+            .par_iter()
+            .map(|&position| forest.get_scenic_score(position))
+            .collect::<Vec<_>>()
+    });
+    let mut max_score = 0;
+    let mut best = (0, 0);
+    for (&position, score) in positions.iter().zip(scores) {
+        if score > max_score {
+            max_score = score;
+            best = position;
+        }
+    }
+    (best, max_score)
+}
+
+// a single frame of the visibility map, trees seen from outside as # and
+// hidden ones as ., with the tree of the best view as @ in part two
+pub fn replay(forest: &Forest, part: u8, sink: &mut dyn FrameSink) {
+    let mut forest = forest.clone();
+    forest.update_tree_seen();
+    let mut frame = Frame::new();
+    for (position, tree) in forest.tree_matrix.iter() {
+        frame.set(position, if tree.seen { '#' } else { '.' });
+    }
+    if part == 1 {
+        frame.set_caption(format!("{} trees visible", forest.get_total_seen()));
+    } else {
+        let (position, score) = best_view(&forest);
+        frame.set(position, '@');
+        frame.focus_on(position);
+        frame.set_caption(format!(
+            "best view from {},{}: {}",
+            position.0, position.1, score
+        ));
+    }
+    sink.frame(&frame);
+}
+
+// size by size forest of random heights
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..size.max(1) {
+        output.extend((0..size.max(1)).map(|_| (b'0' + rng.index(10) as u8) as char));
+        output.push('\n');
+    }
+    output
+}
@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+
+use aoc_common::{next_token, parse_token, Frame, FrameSink, ParseError, Rng};
+
+#[derive(Debug, Clone, Copy)]
+struct Unit {
+    x: i32,
+    y: i32,
+}
+
+struct GameV2 {
+    units: Vec<Unit>,
+    visited_path: HashSet<(i32, i32)>,
+}
+
+impl GameV2 {
+    fn new(number_of_unit: usize) -> GameV2 {
+        GameV2 {
+            units: vec![Unit { x: 0, y: 0 }; number_of_unit],
+            visited_path: HashSet::new(),
+        }
+    }
+    fn move_head(&mut self, direction: char, distance: i32) {
+        for _ in 0..distance {
+            match direction {
+                'U' => self.units[0].y += 1,
+                'D' => self.units[0].y -= 1,
+                'L' => self.units[0].x += 1,
+                'R' => self.units[0].x -= 1,
+                _ => log::warn!("invalid direction {:?}", direction),
+            }
+
+            for i in 1..self.units.len() {
+                if !self.is_head_and_tail_touching(
+                    self.units[i - 1].x,
+                    self.units[i - 1].y,
+                    self.units[i].x,
+                    self.units[i].y,
+                ) {
+                    let head_index = i - 1;
+                    let tail_inedx = i;
+
+                    self.move_tail(head_index, tail_inedx);
+                }
+            }
+
+            // move tail if head and tail are not touching
+
+            let n = self.units.len() - 1;
+            self.visited_path.insert((self.units[n].x, self.units[n].y));
+        }
+    }
+
+    fn is_head_and_tail_touching(
+        &self,
+        head_x: i32,
+        head_y: i32,
+        tail_x: i32,
+        tail_y: i32,
+    ) -> bool {
+        let x_dist = (head_x - tail_x).abs();
+        let y_dist = (head_y - tail_y).abs();
+
+        x_dist <= 1 && y_dist <= 1
+    }
+
+    fn move_tail(&mut self, head_index: usize, tail_index: usize) {
+        let (head_x, head_y) = (self.units[head_index].x, self.units[head_index].y);
+        let (tail_x, tail_y) = (self.units[tail_index].x, self.units[tail_index].y);
+
+        // if tail same row and col , move l,r,u,d
+        if head_x == tail_x || head_y == tail_y {
+            let dx = [0, 0, 1, -1];
+            let dy = [1, -1, 0, 0];
+            for (dx, dy) in dx.iter().zip(dy.iter()) {
+                let (new_tail_x, new_tail_y) = (tail_x + dx, tail_y + dy);
+                if self.is_head_and_tail_touching(head_x, head_y, new_tail_x, new_tail_y) {
+                    self.units[tail_index].x = new_tail_x;
+                    self.units[tail_index].y = new_tail_y;
+                    return;
+                }
+            }
+            panic!("Tail cannot move in same row or col")
+        } else {
+            // else move diagonally
+            let dx = [1, 1, -1, -1];
+            let dy = [1, -1, 1, -1];
+            for (dx, dy) in dx.iter().zip(dy.iter()) {
+                let (new_tail_x, new_tail_y) = (tail_x + dx, tail_y + dy);
+                if self.is_head_and_tail_touching(head_x, head_y, new_tail_x, new_tail_y) {
+                    self.units[tail_index].x = new_tail_x;
+                    self.units[tail_index].y = new_tail_y;
+                    return;
+                }
+            }
+            panic!("Tail cannot move diagonally")
+        }
+    }
+
+    fn get_visited_path(&self) -> &HashSet<(i32, i32)> {
+        &self.visited_path
+    }
+
+    // knots and the tail's trail. up and left count up in the simulation,
+    // so both axes are flipped for the screen
+    fn get_frame(&self) -> Frame {
+        let mut frame = Frame::new();
+        for &(x, y) in &self.visited_path {
+            frame.set((-x, -y), '#');
+        }
+        frame.set((0, 0), 's');
+        // earlier knots are drawn over the ones following them
+        for (index, unit) in self.units.iter().enumerate().rev() {
+            let c = match index {
+                0 => 'H',
+                _ => char::from_digit(index as u32, 10).unwrap_or('T'),
+            };
+            frame.set((-unit.x, -unit.y), c);
+        }
+        frame.focus_on((-self.units[0].x, -self.units[0].y));
+        frame
+    }
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// head motions as (direction, distance)
+pub fn parse(input: &str) -> Result<Vec<(char, i32)>, ParseError> {
+    let mut motions = Vec::new();
+    for line in input.lines() {
+        let mut iter = line.split_whitespace();
+        let token = next_token(input, line, &mut iter, "U, D, L or R")?;
+        let direction = match token {
+            "U" | "D" | "L" | "R" => token.chars().next().unwrap(),
+            _ => return Err(ParseError::at(input, token, "U, D, L or R")),
+        };
+        let token = next_token(input, line, &mut iter, "a distance")?;
+        let distance = parse_token::<i32>(input, token, "a distance")?;
+        motions.push((direction, distance));
+    }
+    Ok(motions)
+}
+
+pub fn solve_part1(motions: &[(char, i32)]) -> usize {
+    simulate(motions, 2)
+}
+
+pub fn solve_part2(motions: &[(char, i32)]) -> usize {
+    simulate(motions, 10)
+}
+
+// return the number of positions visited by the last knot
+pub fn simulate(motions: &[(char, i32)], number_of_unit: usize) -> usize {
+    let mut game = GameV2::new(number_of_unit);
+    for &(direction, distance) in motions {
+        game.move_head(direction, distance);
+    }
+
+    game.get_visited_path().len()
+}
+
+// one frame per step of the head, with the rope of part one or two
+pub fn replay(motions: &[(char, i32)], part: u8, sink: &mut dyn FrameSink) {
+    let number_of_unit = if part == 1 { 2 } else { 10 };
+    let mut game = GameV2::new(number_of_unit);
+    for &(direction, distance) in motions {
+        for step in 1..=distance {
+            game.move_head(direction, 1);
+            if sink.wants_frame() {
+                let mut frame = game.get_frame();
+                frame.set_caption(format!("{} {}/{}", direction, step, distance));
+                sink.frame(&frame);
+            }
+        }
+    }
+    if let (true, Some(&(direction, distance))) = (sink.final_only(), motions.last()) {
+        let mut frame = game.get_frame();
+        frame.set_caption(format!("{} {}/{}", direction, distance, distance));
+        sink.frame(&frame);
+    }
+}
+
+// size moves of up to 20 steps
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..size.max(1) {
+        let direction = rng.choose(&['U', 'D', 'L', 'R']);
+        output += &format!("{} {}\n", direction, rng.range(1, 20));
+    }
+    output
+}
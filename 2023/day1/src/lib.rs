@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use aoc_common::{ParseError, Rng};
+
+const DIGIT_NAMES: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+#[derive(Debug)]
+struct CalibrationValue {
+    first: i32,
+    last: i32,
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    let document = parse(input)?;
+    // spelled out numbers do not count yet
+    for (index, line) in document.iter().enumerate() {
+        if !line.chars().any(|c| c.is_ascii_digit()) {
+            return Err(ParseError::new(index + 1, line.len() + 1, "a digit"));
+        }
+    }
+    Ok(solve_part1(&document).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// calibration document, one line per entry
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut document = Vec::new();
+    for line in input.lines() {
+        if let Some((index, c)) = line
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_alphanumeric())
+        {
+            let token = &line[index..index + c.len_utf8()];
+            return Err(ParseError::at(input, token, "a letter or digit"));
+        }
+        let has_digit = line.chars().any(|c| c.is_ascii_digit())
+            || DIGIT_NAMES.iter().any(|name| line.contains(name));
+        if !has_digit {
+            let end = &line[line.len()..];
+            return Err(ParseError::at(input, end, "a digit or spelled out digit"));
+        }
+        document.push(line.to_string());
+    }
+    Ok(document)
+}
+
+// digits only
+pub fn solve_part1(document: &[String]) -> i32 {
+    compute_result(read_file(document))
+}
+
+// digits and spelled out numbers
+pub fn solve_part2(document: &[String]) -> i32 {
+    compute_result(read_file_part_two(document))
+}
+
+fn read_file_part_two(document: &[String]) -> Vec<CalibrationValue> {
+    let mut calib = Vec::new();
+
+    let mut map_to_check = HashMap::new();
+    for (value, name) in (1..).zip(DIGIT_NAMES) {
+        map_to_check.insert(name, value);
+    }
+
+    for line in document {
+        let mut numbers = Vec::new();
+        for (index, char) in line.char_indices() {
+            for (key, value) in &map_to_check {
+                if line[0..index + 1].ends_with(key) {
+                    numbers.push(*value);
+                }
+            }
+
+            if char.is_ascii_digit() {
+                numbers.push(char.to_digit(10).unwrap() as i32);
+            }
+        }
+        calib.push(CalibrationValue {
+            first: numbers[0],
+            last: numbers[numbers.len() - 1],
+        })
+    }
+    calib
+}
+
+fn compute_result(calib: Vec<CalibrationValue>) -> i32 {
+    let mut result = 0;
+    for value in calib {
+        result += value.first * 10 + value.last;
+    }
+
+    result
+}
+
+fn read_file(document: &[String]) -> Vec<CalibrationValue> {
+    let mut calib = Vec::new();
+    for line in document {
+        let mut numbers = Vec::new();
+        for char in line.chars() {
+            if char.is_ascii_digit() {
+                numbers.push(char.to_digit(10).unwrap() as i32);
+            }
+        }
+        calib.push(CalibrationValue {
+            first: numbers[0],
+            last: numbers[numbers.len() - 1],
+        })
+    }
+    calib
+}
+
+// size lines of letters, digits and spelled out digits, each with at least
+// one digit so part one has something to read
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..size.max(1) {
+        let mut pieces = vec![rng.range(1, 9).to_string()];
+        for _ in 0..rng.range(0, 5) {
+            let piece = match rng.index(3) {
+                0 => rng.range(1, 9).to_string(),
+                1 => rng.choose(&DIGIT_NAMES).to_string(),
+                _ => (0..rng.range(1, 4))
+                    .map(|_| (b'a' + rng.index(26) as u8) as char)
+                    .collect(),
+            };
+            pieces.push(piece);
+        }
+        rng.shuffle(&mut pieces);
+        output += &format!("{}\n", pieces.concat());
+    }
+    output
+}
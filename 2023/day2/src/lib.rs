@@ -0,0 +1,129 @@
+use std::cmp::max;
+
+use aoc_common::{next_token, parse_token, ParseError, Rng};
+
+// cubes of each colour in the bag for part one
+const BAG: CubeSet = CubeSet {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+// cubes of each colour shown in one handful, or held in the bag
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CubeSet {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl CubeSet {
+    fn fits_in(&self, bag: &CubeSet) -> bool {
+        self.red <= bag.red && self.green <= bag.green && self.blue <= bag.blue
+    }
+
+    // wide enough that three counts of u32::MAX cannot overflow it
+    fn power(&self) -> u128 {
+        self.red as u128 * self.green as u128 * self.blue as u128
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    id: u32,
+    handfuls: Vec<CubeSet>,
+}
+
+impl Game {
+    // fewest cubes of each colour the bag could have held
+    fn smallest_bag(&self) -> CubeSet {
+        let mut bag = CubeSet::default();
+        for handful in &self.handfuls {
+            bag.red = max(bag.red, handful.red);
+            bag.green = max(bag.green, handful.green);
+            bag.blue = max(bag.blue, handful.blue);
+        }
+        bag
+    }
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// one game per line, with the handfuls of cubes shown in it
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    let mut games = Vec::new();
+    for line in input.lines() {
+        let (title, handfuls) = match line.split_once(':') {
+            Some(split) => split,
+            None => return Err(ParseError::at(input, &line[line.len()..], "':'")),
+        };
+        let id = match title.strip_prefix("Game ") {
+            Some(id) => parse_token::<u32>(input, id, "a game id")?,
+            None => return Err(ParseError::at(input, title, "Game <id>")),
+        };
+
+        let mut game = Game {
+            id,
+            handfuls: Vec::new(),
+        };
+        for handful in handfuls.split(';') {
+            let mut cube_set = CubeSet::default();
+            for cubes in handful.split(',') {
+                let mut tokens = cubes.split_whitespace();
+                let count = next_token(input, cubes, &mut tokens, "a number of cubes")?;
+                let count = parse_token::<u32>(input, count, "a number of cubes")?;
+                let colour = next_token(input, cubes, &mut tokens, "red, green or blue")?;
+                match colour {
+                    "red" => cube_set.red += count,
+                    "green" => cube_set.green += count,
+                    "blue" => cube_set.blue += count,
+                    _ => return Err(ParseError::at(input, colour, "red, green or blue")),
+                }
+            }
+            game.handfuls.push(cube_set);
+        }
+        games.push(game);
+    }
+    Ok(games)
+}
+
+// sum of the ids of the games possible with the cubes of the bag
+pub fn solve_part1(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .filter(|game| game.handfuls.iter().all(|handful| handful.fits_in(&BAG)))
+        .map(|game| game.id)
+        .sum()
+}
+
+// sum of the powers of the smallest bag of every game
+pub fn solve_part2(games: &[Game]) -> u128 {
+    games.iter().map(|game| game.smallest_bag().power()).sum()
+}
+
+// size games of up to six handfuls, sometimes showing more cubes of a
+// colour than the bag of part one holds
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut output = String::new();
+    for id in 1..=size.max(1) {
+        let mut handfuls = Vec::new();
+        for _ in 0..rng.range(1, 6) {
+            let mut colours = ["red", "green", "blue"];
+            rng.shuffle(&mut colours);
+            let shown = rng.range(1, 3) as usize;
+            let cubes = colours[..shown]
+                .iter()
+                .map(|colour| format!("{} {}", rng.range(1, 16), colour))
+                .collect::<Vec<_>>();
+            handfuls.push(cubes.join(", "));
+        }
+        output += &format!("Game {}: {}\n", id, handfuls.join("; "));
+    }
+    output
+}
@@ -0,0 +1,6 @@
+fn main() -> Result<(), aoc_common::ParseError> {
+    let input = aoc_common::input_from_args("input.txt");
+    println!("Result: {}", day2::part1(&input)?);
+    println!("Result: {}", day2::part2(&input)?);
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_common::{Grid, ParseError, Rng};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Digit(u32),
+    Symbol(char),
+}
+
+// number written left to right on row y, covering start..end
+#[derive(Debug, Clone, Copy)]
+struct Number {
+    value: u32,
+    y: i32,
+    start: i32,
+    end: i32,
+}
+
+pub struct Schematic {
+    grid: Grid<Cell>,
+    numbers: Vec<Number>,
+}
+
+impl Schematic {
+    // symbols touching number, diagonals included
+    fn adjacent_symbols(&self, number: &Number) -> BTreeSet<(i32, i32)> {
+        (number.start..number.end)
+            .flat_map(|x| self.grid.neighbours_8((x, number.y)))
+            .filter(|&position| matches!(self.grid[position], Cell::Symbol(_)))
+            .collect()
+    }
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part1(&parse(input)?).to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    Ok(solve_part2(&parse(input)?).to_string())
+}
+
+// engine schematic of digits, symbols and . for empty space
+pub fn parse(input: &str) -> Result<Schematic, ParseError> {
+    let grid = Grid::parse(input, "a digit, . or a symbol", |c| match c {
+        '.' => Some(Cell::Empty),
+        _ if c.is_ascii_digit() => c.to_digit(10).map(Cell::Digit),
+        _ if c.is_ascii_graphic() => Some(Cell::Symbol(c)),
+        _ => None,
+    })?;
+
+    let mut numbers = Vec::new();
+    for y in 0..grid.height() as i32 {
+        let mut current: Option<Number> = None;
+        for x in 0..=grid.width() as i32 {
+            match (grid.get((x, y)), current.as_mut()) {
+                (Some(Cell::Digit(digit)), Some(number)) => {
+                    number.value = number
+                        .value
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(*digit))
+                        .ok_or_else(|| {
+                            let (line, column) = (y as usize + 1, number.start as usize + 1);
+                            ParseError::new(line, column, "a number that fits in 32 bits")
+                        })?;
+                    number.end = x + 1;
+                }
+                (Some(Cell::Digit(digit)), None) => {
+                    current = Some(Number {
+                        value: *digit,
+                        y,
+                        start: x,
+                        end: x + 1,
+                    })
+                }
+                // anything else, or the end of the row, ends the number
+                _ => numbers.extend(current.take()),
+            }
+        }
+    }
+    Ok(Schematic { grid, numbers })
+}
+
+// sum of the part numbers, the ones next to a symbol
+pub fn solve_part1(schematic: &Schematic) -> u64 {
+    schematic
+        .numbers
+        .iter()
+        .filter(|number| !schematic.adjacent_symbols(number).is_empty())
+        .map(|number| number.value as u64)
+        .sum()
+}
+
+// sum of the gear ratios, the product of the two part numbers next to a *
+// that touches exactly two of them
+pub fn solve_part2(schematic: &Schematic) -> u128 {
+    let mut gears = BTreeMap::<(i32, i32), Vec<u32>>::new();
+    for number in &schematic.numbers {
+        for position in schematic.adjacent_symbols(number) {
+            if schematic.grid[position] == Cell::Symbol('*') {
+                gears.entry(position).or_default().push(number.value);
+            }
+        }
+    }
+    gears
+        .values()
+        .filter(|numbers| numbers.len() == 2)
+        .map(|numbers| numbers[0] as u128 * numbers[1] as u128)
+        .sum()
+}
+
+// size by size schematic, with numbers of up to three digits spread over
+// empty space and symbols dropped in between
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(4);
+    let mut grid = Grid::new(size, size, '.');
+    for y in 0..size as i32 {
+        let mut x = rng.range(0, 3) as i32;
+        while x < size as i32 {
+            let digits = rng.range(1, 3) as i32;
+            if x + digits > size as i32 {
+                break;
+            }
+            for offset in 0..digits {
+                let first = if offset == 0 { 1 } else { 0 };
+                grid[(x + offset, y)] = char::from_digit(rng.range(first, 9) as u32, 10).unwrap();
+            }
+            x += digits + rng.range(1, 6) as i32;
+        }
+    }
+
+    let symbols = ['*', '#', '+', '$', '/', '=', '%', '@', '&', '-'];
+    for _ in 0..size * size / 10 {
+        let position = (rng.index(size) as i32, rng.index(size) as i32);
+        if grid[position] == '.' {
+            grid[position] = *rng.choose(&symbols);
+        }
+    }
+    grid.render(|c| *c)
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+// picture of one step of a simulation, in the coordinates of its day with y
+// growing downwards. cells that are not set are left blank
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    cells: HashMap<(i32, i32), char>,
+    focus: Option<(i32, i32)>,
+    caption: String,
+}
+
+impl Frame {
+    pub fn new() -> Frame {
+        Frame::default()
+    }
+
+    pub fn set(&mut self, position: (i32, i32), c: char) {
+        self.cells.insert(position, c);
+    }
+
+    pub fn get(&self, position: (i32, i32)) -> Option<char> {
+        self.cells.get(&position).copied()
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = ((i32, i32), char)> + '_ {
+        self.cells.iter().map(|(&position, &c)| (position, c))
+    }
+
+    // where the action is, for views too small to show the whole frame
+    pub fn focus(&self) -> Option<(i32, i32)> {
+        self.focus
+    }
+
+    pub fn focus_on(&mut self, position: (i32, i32)) {
+        self.focus = Some(position);
+    }
+
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    pub fn set_caption(&mut self, caption: impl Into<String>) {
+        self.caption = caption.into();
+    }
+
+    // smallest and largest corner of the cells that are set
+    pub fn bounds(&self) -> Option<((i32, i32), (i32, i32))> {
+        let mut positions = self.cells.keys();
+        let &first = positions.next()?;
+        Some(positions.fold((first, first), |(min, max), &(x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        }))
+    }
+
+    // every row of the bounds, blanks filled with background
+    pub fn render(&self, background: char) -> String {
+        let ((min_x, min_y), (max_x, max_y)) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return String::new(),
+        };
+        let mut output = String::new();
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                output.push(self.get((x, y)).unwrap_or(background));
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+// receives the frames of a simulation as it is replayed
+pub trait FrameSink {
+    // whether the coming step is drawn at all, so that days only build the
+    // frames somebody looks at
+    fn wants_frame(&mut self) -> bool {
+        true
+    }
+
+    // whether only the final state is looked at. such sinks turn down every
+    // step in wants_frame, and days send them one closing frame instead
+    fn final_only(&self) -> bool {
+        false
+    }
+
+    fn frame(&mut self, frame: &Frame);
+}
+
+// keeps every frame, mostly for tests
+impl FrameSink for Vec<Frame> {
+    fn frame(&mut self, frame: &Frame) {
+        self.push(frame.clone());
+    }
+}
@@ -0,0 +1,238 @@
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+// north, east, south and west
+const NEIGHBOURS_4: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+// clockwise from north
+const NEIGHBOURS_8: [(i32, i32); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+// dense 2d grid addressed by (x, y), x grows to the right and y grows
+// downwards like the lines of a puzzle input
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // rows shorter than the longest one are filled up with fill, for maps
+    // that leave out trailing blanks
+    pub fn parse_ragged(
+        input: &str,
+        expected: &str,
+        fill: T,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let width = input
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        parse_rows(input, expected, width, Some(&|| fill.clone()), cell)
+    }
+
+    // copy with margin extra cells of fill on every side, so a cell at
+    // (x, y) moves to (x + margin, y + margin)
+    pub fn padded(&self, margin: usize, fill: T) -> Grid<T> {
+        let mut grid = Grid::new(self.width + 2 * margin, self.height + 2 * margin, fill);
+        for ((x, y), value) in self.iter() {
+            grid[(x + margin as i32, y + margin as i32)] = value.clone();
+        }
+        grid
+    }
+}
+
+impl<T> Grid<T> {
+    // one cell per character, cell returns None for a character that does
+    // not belong on the map and expected describes the ones that do. every
+    // row has to be as wide as the first one
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        parse_rows(input, expected, width, None, cell)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (i32, i32)) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, position: (i32, i32)) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: (i32, i32)) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    // row by row, left to right
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x as i32, y as i32)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    // first cell in reading order matching predicate
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<(i32, i32)> {
+        let index = self.cells.iter().position(predicate)?;
+        Some(((index % self.width) as i32, (index / self.width) as i32))
+    }
+
+    // orthogonal neighbours that are inside the grid
+    pub fn neighbours_4(&self, position: (i32, i32)) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.neighbours(position, &NEIGHBOURS_4)
+    }
+
+    // orthogonal and diagonal neighbours that are inside the grid
+    pub fn neighbours_8(&self, position: (i32, i32)) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.neighbours(position, &NEIGHBOURS_8)
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+        }
+    }
+
+    // one line per row, each ending with a newline
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for y in 0..self.height {
+            output.extend(self.row(y).iter().map(&mut cell));
+            output.push('\n');
+        }
+        output
+    }
+
+    fn index_of(&self, position: (i32, i32)) -> Option<usize> {
+        if self.contains(position) {
+            Some(position.1 as usize * self.width + position.0 as usize)
+        } else {
+            None
+        }
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        (x, y): (i32, i32),
+        offsets: &'static [(i32, i32)],
+    ) -> impl Iterator<Item = (i32, i32)> + 'a {
+        offsets
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(move |position| self.contains(*position))
+    }
+}
+
+impl<T> Index<(i32, i32)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (i32, i32)) -> &T {
+        match self.index_of(position) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "{:?} is outside the {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(i32, i32)> for Grid<T> {
+    fn index_mut(&mut self, position: (i32, i32)) -> &mut T {
+        match self.index_of(position) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "{:?} is outside the {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+// rows narrower than width are an error unless there is a fill for them
+fn parse_rows<T>(
+    input: &str,
+    expected: &str,
+    width: usize,
+    fill: Option<&dyn Fn() -> T>,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>, ParseError> {
+    let mut cells = Vec::new();
+    let mut height = 0;
+    for line in input.lines() {
+        let mut count = 0;
+        for (index, c) in line.char_indices() {
+            if count == width {
+                let expected = format!("a row of {} cells", width);
+                return Err(ParseError::at(input, &line[index..], expected));
+            }
+            match cell(c) {
+                Some(value) => cells.push(value),
+                None => return Err(ParseError::at(input, &line[index..], expected)),
+            }
+            count += 1;
+        }
+        if count < width {
+            match fill {
+                Some(fill) => cells.extend((count..width).map(|_| fill())),
+                None => {
+                    let end = &line[line.len()..];
+                    return Err(ParseError::at(
+                        input,
+                        end,
+                        format!("a row of {} cells", width),
+                    ));
+                }
+            }
+        }
+        height += 1;
+    }
+    if width == 0 {
+        return Err(ParseError::new(1, 1, "a row of cells"));
+    }
+    Ok(Grid {
+        width,
+        height,
+        cells,
+    })
+}
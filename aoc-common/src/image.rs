@@ -0,0 +1,126 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::Frame;
+
+// colour of the cells nobody set
+const BACKGROUND: [u8; 3] = [16, 16, 24];
+
+// picture of a frame with every cell drawn as a square of scale by scale
+// pixels, for write-ups where a screenshot of the terminal will not do
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Image {
+    pub fn from_frame(frame: &Frame, scale: usize) -> Image {
+        let scale = scale.max(1);
+        let ((min_x, min_y), (max_x, max_y)) = frame.bounds().unwrap_or(((0, 0), (0, 0)));
+        let columns = (max_x - min_x + 1) as usize;
+        let rows = (max_y - min_y + 1) as usize;
+        let mut image = Image {
+            width: columns * scale,
+            height: rows * scale,
+            pixels: vec![BACKGROUND; columns * rows * scale * scale],
+        };
+        for ((x, y), c) in frame.cells() {
+            let colour = colour(c);
+            let left = (x - min_x) as usize * scale;
+            let top = (y - min_y) as usize * scale;
+            for row in top..top + scale {
+                let start = row * image.width + left;
+                image.pixels[start..start + scale].fill(colour);
+            }
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> [u8; 3] {
+        self.pixels[y * self.width + x]
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+
+    // binary portable pixmap, readable by about anything and trivial to write
+    pub fn write_ppm(&self, out: &mut impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.bytes())
+    }
+
+    pub fn write_png(&self, out: &mut impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.bytes())?;
+        Ok(writer.finish()?)
+    }
+
+    // writes a png or a ppm, going by the extension of path
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        let ppm = match extension {
+            Some("png") => false,
+            Some("ppm") => true,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{}: expected a .png or .ppm file", path.display()),
+                ))
+            }
+        };
+        let mut out = BufWriter::new(File::create(path)?);
+        if ppm {
+            self.write_ppm(&mut out)?;
+        } else {
+            self.write_png(&mut out)?;
+        }
+        out.flush()
+    }
+}
+
+// the same character gets the same colour in every day: rock and walls in
+// grey, sand in yellow, what moves in red and green, open space in blue.
+// digits go from dark to light and anything else gets a colour of its own
+pub fn colour(c: char) -> [u8; 3] {
+    match c {
+        ' ' => BACKGROUND,
+        '.' => [40, 40, 52],
+        '#' => [200, 200, 190],
+        '|' | '-' | '+' => [120, 120, 130],
+        'o' => [230, 190, 90],
+        '@' => [230, 80, 60],
+        'E' | 'H' => [90, 210, 120],
+        's' => [240, 240, 240],
+        '^' | 'v' | '<' | '>' => [110, 170, 230],
+        '~' => [60, 110, 200],
+        '0'..='9' => {
+            let level = 60 + (c as u8 - b'0') * 20;
+            [level, level, level]
+        }
+        _ => {
+            let hash = (c as u32).wrapping_mul(2_654_435_761);
+            [
+                96 + (hash >> 24) as u8 % 160,
+                96 + (hash >> 16) as u8 % 160,
+                96 + (hash >> 8) as u8 % 160,
+            ]
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::{env, fmt, fs, io, path::Path, str::FromStr};
+
+mod frame;
+mod grid;
+mod image;
+mod pool;
+mod rng;
+
+pub use frame::{Frame, FrameSink};
+pub use grid::Grid;
+pub use image::{colour, Image};
+pub use pool::parallel;
+pub use rng::Rng;
+
+// read a whole puzzle input, stopping with the path in the message when it
+// cannot be opened
+pub fn read_input<P: AsRef<Path>>(path: P) -> String {
+    let path = path.as_ref();
+    match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) => panic!("unable to read {}: {}", path.display(), err),
+    }
+}
+
+// puzzle input named by the first command line argument, - for stdin, or
+// default when no argument is given
+pub fn input_from_args(default: &str) -> String {
+    match env::args().nth(1) {
+        Some(source) if source == "-" => read_stdin(),
+        Some(source) => read_input(source),
+        None => read_input(default),
+    }
+}
+
+pub fn read_stdin() -> String {
+    match io::read_to_string(io::stdin()) {
+        Ok(content) => content,
+        Err(err) => panic!("unable to read stdin: {}", err),
+    }
+}
+
+// malformed puzzle input, line and column start at 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    // error located at token, which should be a slice of input so that its
+    // position can be worked out. an empty slice at the end of a line points
+    // just past its last character
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> ParseError {
+        let before = &input[..offset_of(input, token)];
+        let line = before.matches('\n').count() + 1;
+        let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        ParseError::new(line, column, expected)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// byte offset of token within input, falling back to a text search when
+// token was copied out of it
+fn offset_of(input: &str, token: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let position = token.as_ptr() as usize;
+    if position >= start && position + token.len() <= start + input.len() {
+        position - start
+    } else {
+        input.find(token).unwrap_or(input.len())
+    }
+}
+
+// parse token as a T, pointing at it when it is not one
+pub fn parse_token<T: FromStr>(
+    input: &str,
+    token: &str,
+    expected: impl Into<String>,
+) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
+// next item of tokens, pointing at the end of line when there is none left
+pub fn next_token<'a>(
+    input: &str,
+    line: &'a str,
+    tokens: &mut impl Iterator<Item = &'a str>,
+    expected: impl Into<String>,
+) -> Result<&'a str, ParseError> {
+    match tokens.next() {
+        Some(token) => Ok(token),
+        None => Err(ParseError::at(input, &line[line.len()..], expected)),
+    }
+}
@@ -0,0 +1,22 @@
+use std::sync::OnceLock;
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+// runs work, and the rayon iterators within it, on the thread pool the
+// caller is on, such as the one aoc sizes with --jobs. called from anywhere
+// else, like the binary of a day or another crate, it gets a single thread
+// of its own, so that nothing runs in parallel unless somebody asked for it
+pub fn parallel<R: Send>(work: impl FnOnce() -> R + Send) -> R {
+    static SERIAL: OnceLock<ThreadPool> = OnceLock::new();
+    if rayon::current_thread_index().is_some() {
+        return work();
+    }
+    SERIAL
+        .get_or_init(|| {
+            ThreadPoolBuilder::new()
+                .num_threads(1)
+                .build()
+                .expect("a single thread is always there")
+        })
+        .install(work)
+}
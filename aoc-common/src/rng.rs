@@ -0,0 +1,50 @@
+// small seeded generator (splitmix64) for the input generators. it is kept
+// here rather than taken from a crate so a seed produces the same input on
+// every version
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // uniform in low..=high
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "empty range {}..={}", low, high);
+        let span = (high as i128 - low as i128 + 1) as u128;
+        (low as i128 + (self.next_u64() as u128 % span) as i128) as i64
+    }
+
+    // uniform in 0..len
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "no index in an empty range");
+        (self.next_u64() % len as u64) as usize
+    }
+
+    // true once in every denominator calls on average
+    pub fn one_in(&mut self, denominator: u64) -> bool {
+        self.next_u64().is_multiple_of(denominator)
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+// the grid the days share for their maps
+use aoc_common::Grid;
+
+fn cell(c: char) -> Option<char> {
+    matches!(c, '.' | '#').then_some(c)
+}
+
+fn parse(input: &str) -> Result<Grid<char>, String> {
+    Grid::parse(input, ". or #", cell).map_err(|err| err.to_string())
+}
+
+fn sorted(positions: impl Iterator<Item = (i32, i32)>) -> Vec<(i32, i32)> {
+    let mut positions = positions.collect::<Vec<_>>();
+    positions.sort();
+    positions
+}
+
+#[test]
+fn parse_reads_rows() {
+    let grid = parse("#..\n.#.\n").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(0, 0)], '#');
+    assert_eq!(grid[(1, 1)], '#');
+    assert_eq!(grid.get((2, 1)), Some(&'.'));
+    assert_eq!(grid.get((3, 1)), None);
+    assert_eq!(grid.get((0, -1)), None);
+    assert_eq!(grid.row(1), ['.', '#', '.']);
+    assert_eq!(grid.position(|c| *c == '#'), Some((0, 0)));
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        parse("...\n..\n").unwrap_err(),
+        "line 2, column 3: expected a row of 3 cells"
+    );
+    assert_eq!(
+        parse("..\n...\n").unwrap_err(),
+        "line 2, column 3: expected a row of 2 cells"
+    );
+    assert_eq!(
+        parse("..\n.x\n").unwrap_err(),
+        "line 2, column 2: expected . or #"
+    );
+    assert_eq!(
+        parse("").unwrap_err(),
+        "line 1, column 1: expected a row of cells"
+    );
+}
+
+#[test]
+fn parse_ragged_fills_short_rows() {
+    let grid = Grid::parse_ragged(" #\n#..\n\n", ". or #", ' ', |c| {
+        (c == ' ').then_some(' ').or(cell(c))
+    })
+    .unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 3));
+    assert_eq!(grid.render(|c| *c), " # \n#..\n   \n");
+
+    let error = Grid::parse_ragged(".\n.?\n", ". or #", '.', cell).unwrap_err();
+    assert_eq!(error.to_string(), "line 2, column 2: expected . or #");
+}
+
+#[test]
+fn padded_moves_every_cell() {
+    let grid = parse("#.\n").unwrap().padded(1, ' ');
+    assert_eq!((grid.width(), grid.height()), (4, 3));
+    assert_eq!(grid.render(|c| *c), "    \n #. \n    \n");
+    assert_eq!(grid[(1, 1)], '#');
+}
+
+#[test]
+fn neighbours_stay_inside() {
+    let grid = Grid::new(3, 3, 0);
+    assert_eq!(sorted(grid.neighbours_4((0, 0))), [(0, 1), (1, 0)]);
+    assert_eq!(sorted(grid.neighbours_4((1, 0))), [(0, 0), (1, 1), (2, 0)]);
+    assert_eq!(grid.neighbours_4((1, 1)).count(), 4);
+    assert_eq!(sorted(grid.neighbours_8((2, 2))), [(1, 1), (1, 2), (2, 1)]);
+    assert_eq!(grid.neighbours_8((0, 1)).count(), 5);
+    assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+    // clockwise from north
+    assert_eq!(
+        grid.neighbours_8((1, 1)).collect::<Vec<_>>(),
+        [
+            (1, 0),
+            (2, 0),
+            (2, 1),
+            (2, 2),
+            (1, 2),
+            (0, 2),
+            (0, 1),
+            (0, 0)
+        ]
+    );
+}
+
+#[test]
+fn render_gives_back_the_input() {
+    let input = "#..#\n.##.\n....\n";
+    let grid = parse(input).unwrap();
+    assert_eq!(grid.render(|c| *c), input);
+    assert_eq!(
+        grid.map(|c| *c == '#')
+            .render(|wall| if *wall { '#' } else { '.' }),
+        input
+    );
+}
@@ -0,0 +1,142 @@
+// parse and solve of the slow days on both their example and their real
+// input, run with cargo bench -p aoc and filter with a name such as d19/
+use std::{path::Path, time::Duration};
+
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+
+fn read_input(file: &str) -> String {
+    aoc_common::read_input(Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(file))
+}
+
+// one benchmark for parsing and one per part on every input. the slowest
+// parts take seconds a run, so the group runs ten samples instead of
+// criterion's hundred. an input can come with the puzzle parameters its parts
+// are solved with
+macro_rules! bench_day {
+    (@params) => {
+        ()
+    };
+    (@params $params:expr) => {
+        $params
+    };
+    ($c:expr, $name:literal, $day:ident, $part1:expr, $part2:expr, $(($label:literal, $file:literal $(, $params:expr)?)),+) => {{
+        let mut group = $c.benchmark_group($name);
+        configure(&mut group);
+        $(
+            let input = read_input($file);
+            let params = bench_day!(@params $($params)?);
+            group.bench_function(concat!("parse/", $label), |b| {
+                b.iter(|| $day::parse(black_box(&input)).unwrap())
+            });
+            let parsed = $day::parse(&input).unwrap();
+            group.bench_function(concat!("part1/", $label), |b| {
+                b.iter(|| $part1(black_box(&parsed), &params))
+            });
+            group.bench_function(concat!("part2/", $label), |b| {
+                b.iter(|| $part2(black_box(&parsed), &params))
+            });
+        )+
+        group.finish();
+    }};
+}
+
+fn configure(group: &mut BenchmarkGroup<WallTime>) {
+    group.sample_size(10);
+    group.warm_up_time(Duration::from_millis(500));
+    group.measurement_time(Duration::from_secs(5));
+}
+
+fn d15(c: &mut Criterion) {
+    // the example asks about row 10 and a search area of 20
+    bench_day!(
+        c,
+        "d15",
+        d15,
+        |sensor_list: &Vec<d15::Sensor>, params| d15::solve_part1_with(sensor_list, params),
+        |sensor_list: &Vec<d15::Sensor>, params| d15::solve_part2_with(sensor_list, params)
+            .unwrap(),
+        (
+            "example",
+            "2022/d15/test",
+            d15::Params { row: 10, bound: 20 }
+        ),
+        ("input", "2022/d15/input", d15::Params::default())
+    );
+}
+
+fn d16(c: &mut Criterion) {
+    bench_day!(
+        c,
+        "d16",
+        d16,
+        |game, _| d16::solve_part1(game),
+        |game, _| d16::solve_part2(game),
+        ("example", "2022/d16/test"),
+        ("input", "2022/d16/input")
+    );
+}
+
+fn d17(c: &mut Criterion) {
+    bench_day!(
+        c,
+        "d17",
+        d17,
+        |jetstream, _| d17::solve_part1(jetstream),
+        |jetstream, _| d17::solve_part2(jetstream),
+        ("example", "2022/d17/test"),
+        ("input", "2022/d17/input")
+    );
+}
+
+fn d19(c: &mut Criterion) {
+    bench_day!(
+        c,
+        "d19",
+        d19,
+        |blueprint_list: &Vec<d19::BlueprintList>, _| d19::solve_part1(blueprint_list),
+        |blueprint_list: &Vec<d19::BlueprintList>, _| d19::solve_part2(blueprint_list),
+        ("example", "2022/d19/test"),
+        ("input", "2022/d19/input")
+    );
+}
+
+fn d20(c: &mut Criterion) {
+    bench_day!(
+        c,
+        "d20",
+        d20,
+        |list: &Vec<i64>, _| d20::solve_part1(list),
+        |list: &Vec<i64>, _| d20::solve_part2(list),
+        ("example", "2022/d20/test"),
+        ("input", "2022/d20/input")
+    );
+}
+
+fn d23(c: &mut Criterion) {
+    bench_day!(
+        c,
+        "d23",
+        d23,
+        |scan, _| d23::solve_part1(scan),
+        |scan, _| d23::solve_part2(scan),
+        ("example", "2022/d23/test.txt"),
+        ("input", "2022/d23/input.txt")
+    );
+}
+
+fn d24(c: &mut Criterion) {
+    bench_day!(
+        c,
+        "d24",
+        d24,
+        |game, _| d24::solve_part1(game),
+        |game, _| d24::solve_part2(game),
+        ("example", "2022/d24/input.txt"),
+        ("input", "2022/d24/star.txt")
+    );
+}
+
+criterion_group!(heavy_days, d15, d16, d17, d19, d20, d23, d24);
+criterion_main!(heavy_days);
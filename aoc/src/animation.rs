@@ -0,0 +1,112 @@
+use std::{
+    io::{self, Stdout, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{Frame, FrameSink};
+
+// rows and columns kept between the focus and the edge of the viewport
+// before the camera moves
+const MARGIN: i32 = 4;
+
+// draws the frames of a replay in place on the terminal, at most fps of them
+// a second. frames larger than the viewport are cropped around their focus,
+// moving the camera only as much as needed to keep it in view
+pub struct Animation {
+    delay: Duration,
+    every: u64,
+    step: u64,
+    width: i32,
+    height: i32,
+    camera: Option<(i32, i32)>,
+    last: Option<Instant>,
+    out: Stdout,
+}
+
+impl Animation {
+    pub fn new(fps: u32, every: u64, width: u32, height: u32) -> Animation {
+        let mut out = io::stdout();
+        // clear the screen once and hide the cursor while drawing
+        let _ = write!(out, "\x1b[2J\x1b[?25l");
+        Animation {
+            delay: Duration::from_secs(1) / fps.max(1),
+            every: every.max(1),
+            step: 0,
+            width: width.max(1) as i32,
+            // the last row is for the caption
+            height: (height.max(2) - 1) as i32,
+            camera: None,
+            last: None,
+            out,
+        }
+    }
+
+    // top left corner of the viewport for this frame
+    fn place_camera(&mut self, frame: &Frame) -> (i32, i32) {
+        let ((min_x, min_y), (max_x, max_y)) = frame.bounds().unwrap_or(((0, 0), (0, 0)));
+        let focus = frame
+            .focus()
+            .unwrap_or(((min_x + max_x) / 2, (min_y + max_y) / 2));
+        let camera = self
+            .camera
+            .unwrap_or((focus.0 - self.width / 2, focus.1 - self.height / 2));
+        let camera = (
+            follow(camera.0, focus.0, min_x, max_x, self.width),
+            follow(camera.1, focus.1, min_y, max_y, self.height),
+        );
+        self.camera = Some(camera);
+        camera
+    }
+}
+
+// new start of the viewport along one axis: centred on the frame when it
+// fits, otherwise moved just enough to keep the focus away from the edges
+fn follow(start: i32, focus: i32, min: i32, max: i32, size: i32) -> i32 {
+    if max - min < size {
+        return min - (size - (max - min + 1)) / 2;
+    }
+    let margin = MARGIN.min((size - 1) / 2);
+    if focus < start + margin {
+        focus - margin
+    } else if focus > start + size - 1 - margin {
+        focus - size + 1 + margin
+    } else {
+        start
+    }
+}
+
+impl FrameSink for Animation {
+    fn wants_frame(&mut self) -> bool {
+        self.step += 1;
+        (self.step - 1).is_multiple_of(self.every)
+    }
+
+    fn frame(&mut self, frame: &Frame) {
+        let (left, top) = self.place_camera(frame);
+        let mut screen = String::from("\x1b[H");
+        for y in top..top + self.height {
+            for x in left..left + self.width {
+                screen.push(frame.get((x, y)).unwrap_or(' '));
+            }
+            screen.push_str("\x1b[K\n");
+        }
+        screen.push_str(frame.caption());
+        screen.push_str("\x1b[J");
+
+        if let Some(last) = self.last {
+            thread::sleep(self.delay.saturating_sub(last.elapsed()));
+        }
+        let _ = self.out.write_all(screen.as_bytes());
+        let _ = self.out.flush();
+        self.last = Some(Instant::now());
+    }
+}
+
+impl Drop for Animation {
+    fn drop(&mut self) {
+        // the last frame stays on screen, with the cursor back below it
+        let _ = writeln!(self.out, "\x1b[?25h");
+        let _ = self.out.flush();
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{Frame, FrameSink, Image};
+
+// writes the frames of a replay as images. by default only the final state
+// ends up in path, with numbered set every step is written next to it, so
+// out.png becomes out-00001.png, out-00002.png and so on
+pub struct Export {
+    path: PathBuf,
+    scale: usize,
+    numbered: bool,
+    every: u64,
+    step: u64,
+    written: usize,
+    last: Option<Frame>,
+    error: Option<io::Error>,
+}
+
+impl Export {
+    pub fn new(path: PathBuf, scale: usize, numbered: bool, every: u64) -> Export {
+        Export {
+            path,
+            scale,
+            numbered,
+            every: every.max(1),
+            step: 0,
+            written: 0,
+            last: None,
+            error: None,
+        }
+    }
+
+    // writes the final state unless every step was written already, and
+    // returns the number of images written
+    pub fn finish(mut self) -> io::Result<usize> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        if !self.numbered {
+            match self.last.take() {
+                Some(frame) => {
+                    Image::from_frame(&frame, self.scale).save(&self.path)?;
+                    self.written += 1;
+                }
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "the replay produced no frame",
+                    ))
+                }
+            }
+        }
+        Ok(self.written)
+    }
+}
+
+// path with the number of the step added to its name
+fn numbered_path(path: &Path, number: usize) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map_or("frame".into(), |stem| stem.to_string_lossy());
+    let name = match path.extension() {
+        Some(extension) => format!("{}-{:05}.{}", stem, number, extension.to_string_lossy()),
+        None => format!("{}-{:05}", stem, number),
+    };
+    path.with_file_name(name)
+}
+
+impl FrameSink for Export {
+    fn wants_frame(&mut self) -> bool {
+        if self.error.is_some() {
+            return false;
+        }
+        // only the closing frame is kept whatever every says
+        if !self.numbered {
+            return false;
+        }
+        self.step += 1;
+        (self.step - 1).is_multiple_of(self.every)
+    }
+
+    fn final_only(&self) -> bool {
+        !self.numbered
+    }
+
+    fn frame(&mut self, frame: &Frame) {
+        if !self.numbered {
+            self.last = Some(frame.clone());
+            return;
+        }
+        let path = numbered_path(&self.path, self.written + 1);
+        match Image::from_frame(frame, self.scale).save(&path) {
+            Ok(()) => self.written += 1,
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
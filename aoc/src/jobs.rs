@@ -0,0 +1,48 @@
+use std::{collections::BTreeMap, sync::mpsc};
+
+// sizes the thread pool shared by the runner and the days. a single thread
+// unless asked otherwise, so that nothing runs in parallel by default
+pub fn init(jobs: usize) {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build_global()
+        .expect("the thread pool is only built once");
+}
+
+// runs work on a thread of the pool, for the days to spread their own work
+// over the threads like they do within in_order
+pub fn on_pool<R: Send>(work: impl FnOnce() -> R + Send) -> R {
+    rayon::scope(|_| work())
+}
+
+// runs work over every item on the thread pool, handing the results to
+// report in the order of items. a result is reported as soon as it and
+// every one before it are done, so the output reads the same whatever the
+// number of threads
+pub fn in_order<T: Sync, R: Send>(
+    items: &[T],
+    work: impl Fn(&T) -> R + Sync,
+    mut report: impl FnMut(R),
+) {
+    let (sender, receiver) = mpsc::channel();
+    rayon::in_place_scope_fifo(|scope| {
+        for (index, item) in items.iter().enumerate() {
+            let sender = sender.clone();
+            let work = &work;
+            scope.spawn_fifo(move |_| {
+                let _ = sender.send((index, work(item)));
+            });
+        }
+        drop(sender);
+
+        let mut done = BTreeMap::new();
+        let mut next = 0;
+        for (index, result) in receiver {
+            done.insert(index, result);
+            while let Some(result) = done.remove(&next) {
+                report(result);
+                next += 1;
+            }
+        }
+    });
+}
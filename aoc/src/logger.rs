@@ -0,0 +1,37 @@
+use log::{LevelFilter, Log, Metadata, Record};
+
+// writes the diagnostics of the days to stderr so they never mix with the
+// answers. every day logs under its crate name, so targets such as d12
+// keep the output to the days being looked at
+struct Logger {
+    level: LevelFilter,
+    targets: Vec<String>,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let day = metadata.target().split("::").next().unwrap_or_default();
+        metadata.level() <= self.level
+            && (self.targets.is_empty() || self.targets.iter().any(|target| target == day))
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+// warnings only by default, -v adds debug and -vv adds trace
+pub fn init(verbose: u8, targets: Vec<String>) {
+    let level = match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    log::set_max_level(level);
+    log::set_logger(Box::leak(Box::new(Logger { level, targets })))
+        .expect("the logger is only set once");
+}
@@ -0,0 +1,546 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::Rng;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use serde_json::json;
+
+mod alloc;
+mod animation;
+mod export;
+mod jobs;
+mod logger;
+mod params;
+mod solution;
+mod timings;
+mod verify;
+
+use solution::{Day, Failure};
+
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log what the solutions do to stderr, -v for debug and -vv for trace
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    /// Only log this day's crate, such as d12, can be repeated
+    #[arg(long = "log", value_name = "CRATE", global = true)]
+    log_targets: Vec<String>,
+    /// Threads to run days and their independent parts on, one by default.
+    /// The output comes in the same order whatever the number
+    #[arg(long, global = true, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: u16,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day of the year without --day
+    Run {
+        #[arg(long, default_value_t = 2022)]
+        year: u32,
+        /// Day to run, every day of the year is run without it
+        #[arg(long)]
+        day: Option<u32>,
+        /// Only run this part, both parts are run by default
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input or - for stdin, defaults to the input checked in next to the day
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Report parse time, solve time and peak allocation of every part
+        #[arg(long)]
+        timings: bool,
+        /// Output format of the answers or of the timings report, json prints
+        /// one object per answer
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+        /// TOML file of puzzle parameters, with a table such as [2022.15] per day
+        #[arg(long, value_name = "FILE", conflicts_with = "timings")]
+        params: Option<PathBuf>,
+        /// Override a puzzle parameter of the day, such as --param bound=20,
+        /// can be repeated
+        #[arg(long = "param", value_name = "KEY=VALUE", value_parser = params::parse_param, requires = "day", conflicts_with = "timings")]
+        param: Vec<(String, toml::Value)>,
+    },
+    /// Look at the filesystem 2022 day 7 rebuilds from a shell transcript
+    Fs {
+        /// Transcript or - for stdin, defaults to the input of the day
+        #[arg(long, global = true)]
+        input: Option<PathBuf>,
+        #[command(subcommand)]
+        report: FsReport,
+    },
+    /// Print the puzzle parameters of the days that have some, with their defaults
+    Params {
+        #[arg(long, default_value_t = 2022)]
+        year: u32,
+        #[arg(long)]
+        day: Option<u32>,
+    },
+    /// Print a random puzzle input for a day
+    Gen {
+        #[arg(long, default_value_t = 2022)]
+        year: u32,
+        #[arg(long)]
+        day: u32,
+        /// How big the input gets, such as the number of lines or the side of a map
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+        /// Seed of the input, a new one is picked and printed to stderr without it
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Replay a simulation step by step in the terminal
+    Animate {
+        #[arg(long, default_value_t = 2022)]
+        year: u32,
+        #[arg(long)]
+        day: u32,
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Puzzle input or - for stdin, defaults to the input checked in next to the day
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Frames drawn per second
+        #[arg(long, default_value_t = 30)]
+        fps: u32,
+        /// Only draw every nth step, for the long simulations
+        #[arg(long, default_value_t = 1)]
+        every: u64,
+        /// Columns of the viewport, larger frames follow the action
+        #[arg(long, default_value_t = 80)]
+        width: u32,
+        /// Rows of the viewport, including the caption
+        #[arg(long, default_value_t = 24)]
+        height: u32,
+    },
+    /// Save the final state of a simulation as an image, or every step of it
+    Image {
+        #[arg(long, default_value_t = 2022)]
+        year: u32,
+        #[arg(long)]
+        day: u32,
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Puzzle input or - for stdin, defaults to the input checked in next to the day
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Image to write, .png or .ppm
+        #[arg(long, short)]
+        output: PathBuf,
+        /// Pixels per cell of the simulation
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+        /// Write every step as a numbered image next to the output instead
+        #[arg(long)]
+        frames: bool,
+        /// With --frames, only write every nth step
+        #[arg(long, default_value_t = 1, requires = "frames")]
+        every: u64,
+    },
+    /// Check every day against the answers recorded next to its input
+    Verify {
+        /// Only check this year
+        #[arg(long)]
+        year: Option<u32>,
+        /// Only check this day
+        #[arg(long)]
+        day: Option<u32>,
+        /// Record the current answers instead of checking them
+        #[arg(long)]
+        record: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum FsReport {
+    /// Indented tree of every entry, directories with the size of their contents
+    Tree(ReportArgs),
+    /// Size and full path of every directory, like du
+    Du(ReportArgs),
+    /// Nested json of every entry with its name, size, kind and children
+    Json(ReportArgs),
+    /// Graphviz digraph with directories labelled by the size of their contents
+    Dot(ReportArgs),
+    /// Directories to delete to free up space, one alone or a set of them
+    Plan(PlanArgs),
+}
+
+#[derive(Args)]
+struct ReportArgs {
+    /// Order of the entries, size puts the largest first
+    #[arg(long, value_enum, default_value_t = Sort::Name)]
+    sort: Sort,
+    /// Levels shown below the root
+    #[arg(long)]
+    max_depth: Option<usize>,
+    /// Sizes in K, M and G rather than in bytes
+    #[arg(long, short = 'H')]
+    human: bool,
+}
+
+#[derive(Args)]
+struct PlanArgs {
+    /// Size of the disk, 70000000 like the puzzle without it
+    #[arg(long)]
+    capacity: Option<u64>,
+    /// Free space wanted, 30000000 like the puzzle without it
+    #[arg(long)]
+    required: Option<u64>,
+    /// Sizes in K, M and G rather than in bytes
+    #[arg(long, short = 'H')]
+    human: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Sort {
+    Name,
+    Size,
+}
+
+impl ReportArgs {
+    fn options(&self) -> d7::ReportOptions {
+        d7::ReportOptions {
+            sort: match self.sort {
+                Sort::Name => d7::SortBy::Name,
+                Sort::Size => d7::SortBy::Size,
+            },
+            max_depth: self.max_depth,
+            human: self.human,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    logger::init(cli.verbose, cli.log_targets);
+    jobs::init(cli.jobs as usize);
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            timings,
+            format,
+            params,
+            param,
+        } => {
+            let days = select_days(Some(year), day);
+
+            if timings {
+                run_timings(&days, part, input, format);
+                return;
+            }
+
+            let file = match params {
+                Some(path) => params::load(&path).unwrap_or_else(|err| exit_with(err)),
+                None => toml::Table::new(),
+            };
+            let overrides = |day: &Day| {
+                params::for_day(&file, day, &param).unwrap_or_else(|err| {
+                    exit_with(format!("{} day {}: {}", day.year, day.day, err))
+                })
+            };
+
+            if let [day] = days[..] {
+                let (path, content) = read_day_input(day, input);
+                let overrides = overrides(day);
+                match jobs::on_pool(|| run_parts(day, part, &content, &overrides)) {
+                    Ok(answers) => print_answers(day, &answers, format, false),
+                    Err(err) => exit_with(describe(day, &path, err)),
+                }
+                return;
+            }
+
+            // every day is a job of the thread pool, the answers are still
+            // printed day after day
+            let inputs = days
+                .iter()
+                .map(|&day| (day, read_day_input(day, None), overrides(day)))
+                .collect::<Vec<_>>();
+            let mut ok = true;
+            jobs::in_order(
+                &inputs,
+                |(day, (path, content), overrides)| {
+                    (*day, path.clone(), run_parts(day, part, content, overrides))
+                },
+                |(day, path, answers)| match answers {
+                    Ok(answers) => print_answers(day, &answers, format, true),
+                    Err(err) => {
+                        eprintln!("{}", describe(day, &path, err));
+                        ok = false;
+                    }
+                },
+            );
+            if !ok {
+                std::process::exit(1);
+            }
+        }
+        Command::Gen {
+            year,
+            day,
+            size,
+            seed,
+        } => {
+            let day = select_days(Some(year), Some(day))[0];
+            let seed = seed.unwrap_or_else(|| {
+                let seed = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |elapsed| elapsed.as_nanos() as u64);
+                eprintln!("seed: {}", seed);
+                seed
+            });
+            print!("{}", day.solution.generate(&mut Rng::new(seed), size));
+        }
+        Command::Animate {
+            year,
+            day,
+            part,
+            input,
+            fps,
+            every,
+            width,
+            height,
+        } => {
+            let day = select_days(Some(year), Some(day))[0];
+            let replay = day_replay(day);
+            let (path, content) = read_day_input(day, input);
+            let result = {
+                let mut animation = animation::Animation::new(fps, every, width, height);
+                jobs::on_pool(|| replay(&content, part, &mut animation))
+            };
+            if let Err(err) = result {
+                exit_with(format!("{}: {}", path.display(), err));
+            }
+        }
+        Command::Image {
+            year,
+            day,
+            part,
+            input,
+            output,
+            scale,
+            frames,
+            every,
+        } => {
+            let day = select_days(Some(year), Some(day))[0];
+            let replay = day_replay(day);
+            let (path, content) = read_day_input(day, input);
+            let mut export = export::Export::new(output, scale, frames, every);
+            if let Err(err) = jobs::on_pool(|| replay(&content, part, &mut export)) {
+                exit_with(format!("{}: {}", path.display(), err));
+            }
+            match export.finish() {
+                Ok(written) => eprintln!("{} images written", written),
+                Err(err) => exit_with(format!("unable to write images: {}", err)),
+            }
+        }
+        Command::Fs { input, report } => {
+            let day = select_days(Some(2022), Some(7))[0];
+            let (path, content) = read_day_input(day, input);
+            let graph = match d7::parse(&content) {
+                Ok(graph) => graph,
+                Err(err) => exit_with(format!("{}: {}", path.display(), err)),
+            };
+            match report {
+                FsReport::Tree(args) => print!("{}", d7::tree(&graph, &args.options())),
+                FsReport::Du(args) => print!("{}", d7::du(&graph, &args.options())),
+                FsReport::Json(args) => print!("{}", d7::json(&graph, &args.options())),
+                FsReport::Dot(args) => print!("{}", d7::dot(&graph, &args.options())),
+                FsReport::Plan(args) => {
+                    let defaults = d7::Params::default();
+                    let params = d7::Params {
+                        disk_size: args.capacity.unwrap_or(defaults.disk_size),
+                        required_space: args.required.unwrap_or(defaults.required_space),
+                        ..defaults
+                    };
+                    print!(
+                        "{}",
+                        d7::plan_report(&d7::plan(&graph, &params), args.human)
+                    );
+                }
+            }
+        }
+        Command::Params { year, day } => {
+            for day in select_days(Some(year), day) {
+                if let Some(defaults) = day.solution.params() {
+                    params::print_defaults(day, &defaults);
+                }
+            }
+        }
+        Command::Verify { year, day, record } => {
+            let mut ok = true;
+            let root = repo_root();
+            jobs::in_order(
+                &select_days(year, day),
+                |day| verify::verify_day(day, &root, record),
+                |(day_ok, lines)| {
+                    for line in lines {
+                        println!("{}", line);
+                    }
+                    ok &= day_ok;
+                },
+            );
+            if !ok {
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+fn select_days(year: Option<u32>, day: Option<u32>) -> Vec<&'static Day> {
+    let days = solution::DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|year| d.year == year))
+        .filter(|d| day.is_none_or(|day| d.day == day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        let message = match (year, day) {
+            (Some(year), Some(day)) => format!("no solution for year {} day {}", year, day),
+            (Some(year), None) => format!("no solutions for year {}", year),
+            (None, Some(day)) => format!("no solution for day {}", day),
+            (None, None) => "no solutions".to_string(),
+        };
+        exit_with(message);
+    }
+    days
+}
+
+fn day_replay(day: &Day) -> solution::Replay {
+    match day.solution.replay() {
+        Some(replay) => replay,
+        None => exit_with(format!(
+            "year {} day {} has no simulation to replay",
+            day.year, day.day
+        )),
+    }
+}
+
+// errors of the input point at the file, those of the parameters at the day
+fn describe(day: &Day, path: &Path, failure: Failure) -> String {
+    match failure {
+        Failure::Input(err) => format!("{}: {}", path.display(), err),
+        Failure::Params(_) => format!("{} day {}: {}", day.year, day.day, failure),
+    }
+}
+
+fn exit_with(message: String) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(1)
+}
+
+// a missing or unreadable input panics with its path, like the day binaries
+fn read_day_input(day: &Day, input: Option<PathBuf>) -> (PathBuf, String) {
+    let path = input.unwrap_or_else(|| repo_root().join(day.input));
+    let content = if path.as_os_str() == "-" {
+        aoc_common::read_stdin()
+    } else {
+        aoc_common::read_input(&path)
+    };
+    (path, content)
+}
+
+// the report is printed once every part has run, so that it does not mix
+// with what the solutions print along the way. days are measured one after
+// the other even with --jobs, the peak allocation is counted for the whole
+// process
+fn run_timings(days: &[&Day], part: Option<u8>, input: Option<PathBuf>, format: Format) {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut reports = Vec::new();
+    for day in days {
+        let (path, content) = read_day_input(day, input.clone());
+        for part in parts.iter() {
+            match jobs::on_pool(|| timings::measure(day, *part, &content)) {
+                Ok(report) => reports.push(report),
+                Err(failure) => exit_with(describe(day, &path, failure)),
+            }
+        }
+    }
+    match format {
+        Format::Table => timings::print_table(&reports),
+        Format::Json => timings::print_json(&reports),
+    }
+}
+
+// answer of a part and how long it took
+struct Answer {
+    part: u8,
+    answer: String,
+    elapsed: Duration,
+}
+
+fn run_parts(
+    day: &Day,
+    part: Option<u8>,
+    content: &str,
+    overrides: &toml::Table,
+) -> Result<Vec<Answer>, Failure> {
+    let mut answers = Vec::new();
+    for current in [1, 2] {
+        if part.unwrap_or(current) != current {
+            continue;
+        }
+        let start = Instant::now();
+        let answer = day.solution.part_with(current, content, overrides)?;
+        answers.push(Answer {
+            part: current,
+            answer,
+            elapsed: start.elapsed(),
+        });
+    }
+    Ok(answers)
+}
+
+// with_day puts the day above its answers in the table, for runs of more
+// than one day
+fn print_answers(day: &Day, answers: &[Answer], format: Format, with_day: bool) {
+    if with_day && matches!(format, Format::Table) {
+        println!("{} day {}", day.year, day.day);
+    }
+    for answer in answers {
+        match format {
+            Format::Table => print_answer(answer.part, &answer.answer),
+            Format::Json => println!(
+                "{}",
+                json!({
+                    "year": day.year,
+                    "day": day.day,
+                    "part": answer.part,
+                    "answer": answer.answer,
+                    "elapsed_ms": answer.elapsed.as_secs_f64() * 1000.0,
+                })
+            ),
+        }
+    }
+}
+
+fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn print_answer(part: u8, answer: &str) {
+    // drawings such as the crt output start on their own line
+    if answer.contains('\n') {
+        println!("part {}:\n{}", part, answer);
+    } else {
+        println!("part {}: {}", part, answer);
+    }
+}
@@ -0,0 +1,62 @@
+use std::{fs, path::Path};
+
+use crate::solution::Day;
+
+// overrides of the puzzle parameters, read from a file with one table per
+// day such as
+//
+//     [2022.15]
+//     row = 10
+//     bound = 20
+//
+// where --param key=value on the command line wins over the file
+pub fn load(path: &Path) -> Result<toml::Table, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("unable to read {}: {}", path.display(), err))?;
+    content
+        .parse::<toml::Table>()
+        .map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+// overrides of day, from its table of the file and then from the command line
+pub fn for_day(
+    file: &toml::Table,
+    day: &Day,
+    params: &[(String, toml::Value)],
+) -> Result<toml::Table, String> {
+    let section = file
+        .get(&day.year.to_string())
+        .and_then(|year| year.get(day.day.to_string()));
+    let mut overrides = match section {
+        Some(toml::Value::Table(table)) => table.clone(),
+        Some(_) => return Err(format!("[{}.{}] should be a table", day.year, day.day)),
+        None => toml::Table::new(),
+    };
+    for (key, value) in params {
+        overrides.insert(key.clone(), value.clone());
+    }
+    Ok(overrides)
+}
+
+// key=value, the value read as toml so that numbers stay numbers, and as a
+// plain string when it is not valid toml
+pub fn parse_param(param: &str) -> Result<(String, toml::Value), String> {
+    let (key, value) = match param.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => (key.trim(), value.trim()),
+        _ => return Err(format!("expected key=value, got {:?}", param)),
+    };
+    let value = match format!("value = {}", value).parse::<toml::Table>() {
+        Ok(mut table) => table.remove("value").unwrap(),
+        Err(_) => toml::Value::String(value.to_string()),
+    };
+    Ok((key.to_string(), value))
+}
+
+// the defaults of day as a table of the parameters file
+pub fn print_defaults(day: &Day, defaults: &toml::Table) {
+    println!("[{}.{}]", day.year, day.day);
+    println!(
+        "{}",
+        toml::to_string(defaults).expect("parameters are a table")
+    );
+}
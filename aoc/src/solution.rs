@@ -0,0 +1,248 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{FrameSink, ParseError, Rng};
+
+// answer of a part along with the time spent parsing and solving it
+pub struct Timing {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+// replays a part of a day step by step, for the days with something to watch
+pub type Replay = fn(&str, u8, &mut dyn FrameSink) -> Result<(), ParseError>;
+
+// why a part could not run with the parameters it was given
+#[derive(Debug)]
+pub enum Failure {
+    Input(ParseError),
+    Params(String),
+}
+
+impl From<ParseError> for Failure {
+    fn from(err: ParseError) -> Failure {
+        Failure::Input(err)
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Input(err) => write!(f, "{}", err),
+            Failure::Params(message) => write!(f, "invalid parameters: {}", message),
+        }
+    }
+}
+
+// what the solvers of the days return, turned into the answer printed. a
+// solver that can fail on input that parses but that its part cannot use,
+// or on parameters it has no answer for, returns a result
+pub trait ToAnswer {
+    fn to_answer(self) -> Result<String, Failure>;
+}
+
+macro_rules! to_answer {
+    ($($answer:ty),*) => {
+        $(impl ToAnswer for $answer {
+            fn to_answer(self) -> Result<String, Failure> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+to_answer!(i32, i64, i128, u32, u64, u128, usize, String);
+
+impl<T: ToAnswer> ToAnswer for Result<T, ParseError> {
+    fn to_answer(self) -> Result<String, Failure> {
+        self?.to_answer()
+    }
+}
+
+// parameters that leave a part without an answer
+impl<T: ToAnswer> ToAnswer for Result<T, String> {
+    fn to_answer(self) -> Result<String, Failure> {
+        self.map_err(Failure::Params)?.to_answer()
+    }
+}
+
+// every day exposes part1 and part2 over the raw puzzle input
+pub trait Solution: Sync {
+    fn part1(&self, input: &str) -> Result<String, Failure>;
+    fn part2(&self, input: &str) -> Result<String, Failure>;
+    // same as part1 or part2 with parse and solve timed separately
+    fn timed(&self, part: u8, input: &str) -> Result<Timing, Failure>;
+    // random puzzle input, size is read by every day in its own way
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+    fn replay(&self) -> Option<Replay> {
+        None
+    }
+    // default puzzle parameters of the days that have some
+    fn params(&self) -> Option<toml::Table> {
+        None
+    }
+    // part1 or part2 with some of the puzzle parameters overridden, days
+    // without parameters take no overrides at all
+    fn part_with(&self, part: u8, input: &str, overrides: &toml::Table) -> Result<String, Failure> {
+        if let Some(name) = overrides.keys().next() {
+            return Err(Failure::Params(format!(
+                "unknown field `{}`, the day has no parameters",
+                name
+            )));
+        }
+        match part {
+            1 => self.part1(input),
+            _ => self.part2(input),
+        }
+    }
+}
+
+// solution!(D14, d14, replay, params) also hooks up the replay or the
+// puzzle parameters of a day
+macro_rules! solution {
+    (@replay $day:ident) => {
+        fn replay(&self) -> Option<Replay> {
+            Some(|input, part, sink| {
+                $day::replay(&$day::parse(input)?, part, sink);
+                Ok(())
+            })
+        }
+    };
+    (@params $day:ident) => {
+        fn params(&self) -> Option<toml::Table> {
+            Some(toml::Table::try_from($day::Params::default()).expect("parameters are a table"))
+        }
+
+        fn part_with(
+            &self,
+            part: u8,
+            input: &str,
+            overrides: &toml::Table,
+        ) -> Result<String, Failure> {
+            let params: $day::Params = toml::Value::Table(overrides.clone())
+                .try_into()
+                .map_err(|err: toml::de::Error| Failure::Params(err.message().to_string()))?;
+            let parsed = $day::parse(input)?;
+            match part {
+                1 => $day::solve_part1_with(&parsed, &params).to_answer(),
+                _ => $day::solve_part2_with(&parsed, &params).to_answer(),
+            }
+        }
+    };
+    ($name:ident, $day:ident $(, $extra:ident)*) => {
+        pub struct $name;
+
+        impl Solution for $name {
+            fn part1(&self, input: &str) -> Result<String, Failure> {
+                $day::solve_part1(&$day::parse(input)?).to_answer()
+            }
+
+            fn part2(&self, input: &str) -> Result<String, Failure> {
+                $day::solve_part2(&$day::parse(input)?).to_answer()
+            }
+
+            fn timed(&self, part: u8, input: &str) -> Result<Timing, Failure> {
+                let start = Instant::now();
+                let parsed = $day::parse(input)?;
+                let parse = start.elapsed();
+
+                let start = Instant::now();
+                let answer = match part {
+                    1 => $day::solve_part1(&parsed).to_answer()?,
+                    _ => $day::solve_part2(&parsed).to_answer()?,
+                };
+                Ok(Timing {
+                    answer,
+                    parse,
+                    solve: start.elapsed(),
+                })
+            }
+
+            fn generate(&self, rng: &mut Rng, size: usize) -> String {
+                $day::generate(rng, size)
+            }
+
+            $(solution!(@$extra $day);)*
+        }
+    };
+}
+
+solution!(D1, d1);
+solution!(D2, d2);
+solution!(D3, d3);
+solution!(D4, d4);
+solution!(D5, d5);
+solution!(D6, d6);
+solution!(D7, d7, params);
+solution!(D8, d8, replay);
+solution!(D9, d9, replay);
+solution!(D10, d10, replay);
+solution!(D11, d11, params);
+solution!(D12, d12);
+solution!(D13, d13);
+solution!(D14, d14, replay);
+solution!(D15, d15, params);
+solution!(D16, d16, params);
+solution!(D17, d17, replay, params);
+solution!(D18, d18, replay);
+solution!(D19, d19, params);
+solution!(D20, d20, params);
+solution!(D21, d21);
+solution!(D22, d22);
+solution!(D23, d23, replay);
+solution!(D24, d24, replay);
+solution!(Day1, day1);
+solution!(Day2, day2);
+solution!(Day3, day3);
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    // default input, relative to the repository root
+    pub input: &'static str,
+    pub solution: &'static dyn Solution,
+}
+
+impl Day {
+    const fn new(year: u32, day: u32, input: &'static str, solution: &'static dyn Solution) -> Day {
+        Day {
+            year,
+            day,
+            input,
+            solution,
+        }
+    }
+}
+
+pub static DAYS: &[Day] = &[
+    Day::new(2022, 1, "2022/d1/input", &D1),
+    Day::new(2022, 2, "2022/d2/input", &D2),
+    Day::new(2022, 3, "2022/d3/input", &D3),
+    Day::new(2022, 4, "2022/d4/input", &D4),
+    Day::new(2022, 5, "2022/d5/input", &D5),
+    Day::new(2022, 6, "2022/d6/input", &D6),
+    Day::new(2022, 7, "2022/d7/input", &D7),
+    Day::new(2022, 8, "2022/d8/input", &D8),
+    Day::new(2022, 9, "2022/d9/input", &D9),
+    Day::new(2022, 10, "2022/d10/input", &D10),
+    Day::new(2022, 11, "2022/d11/input", &D11),
+    Day::new(2022, 12, "2022/d12/input", &D12),
+    Day::new(2022, 13, "2022/d13/input", &D13),
+    Day::new(2022, 14, "2022/d14/input", &D14),
+    Day::new(2022, 15, "2022/d15/input", &D15),
+    Day::new(2022, 16, "2022/d16/input", &D16),
+    Day::new(2022, 17, "2022/d17/input", &D17),
+    Day::new(2022, 18, "2022/d18/input", &D18),
+    Day::new(2022, 19, "2022/d19/input", &D19),
+    Day::new(2022, 20, "2022/d20/input", &D20),
+    Day::new(2022, 21, "2022/d21/input", &D21),
+    Day::new(2022, 22, "2022/d22/input", &D22),
+    Day::new(2022, 23, "2022/d23/input.txt", &D23),
+    Day::new(2022, 24, "2022/d24/star.txt", &D24),
+    Day::new(2023, 1, "2023/day1/test.txt", &Day1),
+    Day::new(2023, 2, "2023/day2/input.txt", &Day2),
+    Day::new(2023, 3, "2023/day3/input.txt", &Day3),
+];
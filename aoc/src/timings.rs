@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::{
+    alloc,
+    solution::{Day, Failure},
+};
+
+// how long a part took and the most memory it had allocated at once
+pub struct Report {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+    pub peak_bytes: usize,
+}
+
+pub fn measure(day: &Day, part: u8, input: &str) -> Result<Report, Failure> {
+    let baseline = alloc::reset_peak();
+    day.solution.timed(part, input).map(|timing| Report {
+        year: day.year,
+        day: day.day,
+        part,
+        answer: timing.answer,
+        parse: timing.parse,
+        solve: timing.solve,
+        peak_bytes: alloc::peak().saturating_sub(baseline),
+    })
+}
+
+pub fn print_table(reports: &[Report]) {
+    println!(
+        "{:>4} {:>3} {:>4} {:>12} {:>12} {:>10}",
+        "year", "day", "part", "parse", "solve", "peak"
+    );
+    for report in reports {
+        println!(
+            "{:>4} {:>3} {:>4} {:>12} {:>12} {:>10}",
+            report.year,
+            report.day,
+            report.part,
+            format_duration(report.parse),
+            format_duration(report.solve),
+            format_bytes(report.peak_bytes)
+        );
+    }
+}
+
+pub fn print_json(reports: &[Report]) {
+    let reports = reports
+        .iter()
+        .map(|report| {
+            json!({
+                "year": report.year,
+                "day": report.day,
+                "part": report.part,
+                "answer": report.answer,
+                "parse_ms": as_ms(report.parse),
+                "solve_ms": as_ms(report.solve),
+                "peak_bytes": report.peak_bytes,
+            })
+        })
+        .collect::<Vec<_>>();
+    println!("{}", serde_json::Value::Array(reports));
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn format_duration(duration: Duration) -> String {
+    if duration.as_secs() > 0 {
+        format!("{:.2} s", duration.as_secs_f64())
+    } else {
+        format!("{:.3} ms", as_ms(duration))
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, units[unit])
+    }
+}
@@ -0,0 +1,116 @@
+use std::{fs, path::Path};
+
+use serde_json::{json, Value};
+
+use crate::solution::Day;
+
+// answers of a day's own input, kept as answers.json next to it
+#[derive(Default)]
+struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+fn load_answers(path: &Path) -> Result<Answers, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(_) => return Ok(Answers::default()),
+    };
+    let value: Value =
+        serde_json::from_str(&content).map_err(|err| format!("{}: {}", path.display(), err))?;
+    let part = |key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+    Ok(Answers {
+        part1: part("part1"),
+        part2: part("part2"),
+    })
+}
+
+fn save_answers(path: &Path, answers: &Answers) -> Result<(), String> {
+    let value = json!({
+        "part1": answers.part1,
+        "part2": answers.part2,
+    });
+    let content = serde_json::to_string_pretty(&value).unwrap() + "\n";
+    fs::write(path, content).map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+// run both parts of day and compare them with the recorded answers, or
+// record them instead. false when an answer changed or the day failed,
+// along with one line of report per part
+pub fn verify_day(day: &Day, root: &Path, record: bool) -> (bool, Vec<String>) {
+    let mut lines = Vec::new();
+    let label = format!("{} day {}", day.year, day.day);
+    let input_path = root.join(day.input);
+    let answers_path = input_path.with_file_name("answers.json");
+
+    let input = match fs::read_to_string(&input_path) {
+        Ok(input) => input,
+        Err(err) => {
+            lines.push(format!(
+                "{}: unable to read {}: {}",
+                label,
+                input_path.display(),
+                err
+            ));
+            return (false, lines);
+        }
+    };
+    let mut answers = match load_answers(&answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            lines.push(format!("{}: {}", label, err));
+            return (false, lines);
+        }
+    };
+
+    let mut ok = true;
+    let parts = [
+        (1, day.solution.part1(&input), &mut answers.part1),
+        (2, day.solution.part2(&input), &mut answers.part2),
+    ];
+    for (part, answer, recorded) in parts {
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(err) => {
+                lines.push(format!(
+                    "{} part {}: {}: {}",
+                    label,
+                    part,
+                    input_path.display(),
+                    err
+                ));
+                ok = false;
+                continue;
+            }
+        };
+        if record {
+            lines.push(format!("{} part {}: recorded {:?}", label, part, answer));
+            *recorded = Some(answer);
+            continue;
+        }
+        match recorded {
+            Some(expected) if *expected == answer => {
+                lines.push(format!("{} part {}: ok", label, part))
+            }
+            Some(expected) => {
+                lines.push(format!(
+                    "{} part {}: CHANGED, expected {:?} but got {:?}",
+                    label, part, expected, answer
+                ));
+                ok = false;
+            }
+            None => lines.push(format!(
+                "{} part {}: no recorded answer, got {:?}",
+                label, part, answer
+            )),
+        }
+    }
+
+    if record {
+        if let Err(err) = save_answers(&answers_path, &answers) {
+            lines.push(format!("{}: {}", label, err));
+            return (false, lines);
+        }
+    }
+    (ok, lines)
+}
@@ -0,0 +1,13 @@
+// helpers shared by the integration tests, each of which only uses some
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+// the repository root, which the paths of the examples are relative to
+pub fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+pub fn read_example(file: &str) -> String {
+    aoc_common::read_input(repo_root().join(file))
+}
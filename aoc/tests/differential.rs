@@ -0,0 +1,246 @@
+// the days that rely on shortcuts are checked against slow reference
+// solvers that try everything, on many small random inputs. a failure
+// prints the input so it can be turned into a regression test
+use aoc_common::Rng;
+
+// sensors around one position left uncovered, with more sensors added
+// until it is the only uncovered position of the search area
+fn small_sensors(rng: &mut Rng, bound: i32) -> String {
+    let distance = |a: (i32, i32), b: (i32, i32)| (a.0 - b.0).abs() + (a.1 - b.1).abs();
+    let distress = (
+        rng.range(0, bound as i64) as i32,
+        rng.range(0, bound as i64) as i32,
+    );
+    let mut sensors = Vec::<((i32, i32), i32)>::new();
+    let mut input = String::new();
+    for y in 0..=bound {
+        for x in 0..=bound {
+            let covered = sensors
+                .iter()
+                .any(|&(sensor, radius)| distance(sensor, (x, y)) <= radius);
+            if covered || (x, y) == distress {
+                continue;
+            }
+            // a sensor somewhere near the gap, reaching just short of the
+            // distress beacon
+            let sensor = (x + rng.range(-2, 2) as i32, y + rng.range(-2, 2) as i32);
+            let radius = distance(sensor, distress) - 1;
+            if radius < distance(sensor, (x, y)) {
+                // too close to the distress beacon, cover the gap on its own
+                sensors.push(((x, y), 0));
+                input += &format!("Sensor at x={x}, y={y}: closest beacon is at x={x}, y={y}\n");
+                continue;
+            }
+            let dx = rng.range(-radius as i64, radius as i64) as i32;
+            let dy = (radius - dx.abs()) * if rng.one_in(2) { 1 } else { -1 };
+            sensors.push((sensor, radius));
+            input += &format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                sensor.0,
+                sensor.1,
+                sensor.0 + dx,
+                sensor.1 + dy
+            );
+        }
+    }
+    input
+}
+
+#[test]
+fn d15_matches_naive() {
+    for seed in 0..200 {
+        let mut rng = Rng::new(seed);
+        let bound = rng.range(1, 30) as i32;
+        let input = small_sensors(&mut rng, bound);
+        let sensor_list = d15::parse(&input).unwrap();
+        for y in -2..=bound + 2 {
+            assert_eq!(
+                d15::solution_1(&sensor_list, y),
+                d15::naive_solution_1(&sensor_list, y),
+                "seed {} row {}\n{}",
+                seed,
+                y,
+                input
+            );
+        }
+        assert_eq!(
+            d15::solution_2(&sensor_list, bound),
+            d15::naive_solution_2(&sensor_list, bound),
+            "seed {}\n{}",
+            seed,
+            input
+        );
+    }
+}
+
+// a handful of valves, half of them with a flow rate, so the search of the
+// reference solver stays small
+fn small_valves(rng: &mut Rng) -> String {
+    let count = rng.range(2, 7) as usize;
+    let names = ["AA", "BB", "CC", "DD", "EE", "FF", "GG"];
+    let mut tunnels = vec![Vec::new(); count];
+    for valve in 1..count {
+        let other = rng.index(valve);
+        tunnels[valve].push(other);
+        tunnels[other].push(valve);
+    }
+    for _ in 0..rng.index(count) {
+        let (from, to) = (rng.index(count), rng.index(count));
+        if from != to && !tunnels[from].contains(&to) {
+            tunnels[from].push(to);
+            tunnels[to].push(from);
+        }
+    }
+
+    let mut input = String::new();
+    for (valve, neighbours) in tunnels.iter().enumerate() {
+        let flow_rate = if rng.one_in(2) { rng.range(1, 25) } else { 0 };
+        let neighbours = neighbours
+            .iter()
+            .map(|&neighbour| names[neighbour])
+            .collect::<Vec<_>>();
+        input += &format!(
+            "Valve {} has flow rate={}; tunnels lead to valves {}\n",
+            names[valve],
+            flow_rate,
+            neighbours.join(", ")
+        );
+    }
+    input
+}
+
+#[test]
+fn d16_matches_naive() {
+    for seed in 0..100 {
+        let input = small_valves(&mut Rng::new(seed));
+        let game = d16::parse(&input).unwrap();
+        assert_eq!(
+            d16::solve_part1(&game),
+            d16::naive_max_pressure(&game, 30, false),
+            "seed {} part 1\n{}",
+            seed,
+            input
+        );
+        assert_eq!(
+            d16::solve_part2(&game),
+            d16::naive_max_pressure(&game, 26, true),
+            "seed {} part 2\n{}",
+            seed,
+            input
+        );
+    }
+}
+
+#[test]
+fn d17_matches_naive() {
+    for seed in 0..40 {
+        let mut rng = Rng::new(seed);
+        let size = rng.range(1, 40) as usize;
+        let input = d17::generate(&mut rng, size);
+        let jetstream = d17::parse(&input).unwrap();
+        for n in [1, 5, 100, 2022, rng.range(1, 5000)] {
+            assert_eq!(
+                d17::solution(&jetstream, n),
+                d17::naive_solution(&jetstream, n),
+                "seed {} after {} rocks\n{}",
+                seed,
+                n,
+                input
+            );
+        }
+    }
+}
+
+// jets that keep a column open deeper than the surface looked at, which
+// must not pass for a cycle
+#[test]
+fn d17_open_columns_match_naive() {
+    for input in ["<", ">", "<<>", "<<<<>", ">>><"] {
+        let jetstream = d17::parse(input).unwrap();
+        for n in [100, 2022, 3001] {
+            assert_eq!(
+                d17::solution(&jetstream, n),
+                d17::naive_solution(&jetstream, n),
+                "{} after {} rocks",
+                input,
+                n
+            );
+        }
+    }
+}
+
+// the same jets for part two, where a cycle has to be found all the same.
+// every five rocks add eleven rows once the tower has settled in
+#[test]
+fn d17_open_columns_part2() {
+    for input in ["<", ">>><"] {
+        let jetstream = d17::parse(input).unwrap();
+        assert_eq!(d17::naive_solution(&jetstream, 10000), 22000);
+        assert_eq!(d17::solve_part2(&jetstream), 2200000000000, "{}", input);
+    }
+}
+
+// cheap robots, so geodes get opened within the short times the reference
+// solver can search. the cheaper they get the more states it goes through
+fn small_blueprint(rng: &mut Rng, id: usize, cheapest: i64) -> String {
+    format!(
+        "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+        id,
+        rng.range(cheapest, 4),
+        rng.range(cheapest, 4),
+        rng.range(cheapest, 4),
+        rng.range(cheapest, 6),
+        rng.range(cheapest, 4),
+        rng.range(cheapest, 6)
+    )
+}
+
+#[test]
+fn d19_matches_naive() {
+    for seed in 0..100 {
+        let mut rng = Rng::new(seed);
+        let input = small_blueprint(&mut rng, 1, 1);
+        let time = rng.range(1, 12) as u32;
+        let blueprints = &d19::parse(&input).unwrap()[0];
+        assert_eq!(
+            d19::game_1(d19::StateMachine::new(1, time), blueprints),
+            d19::naive_max_geodes(blueprints, time),
+            "seed {} in {} minutes\n{}",
+            seed,
+            time,
+            input
+        );
+    }
+}
+
+// part two through its parameters, with the longer times that make the
+// pruning of game_1 matter, on the first few of some blueprints. robots
+// cost at least two of everything to keep the reference solver quick
+#[test]
+fn d19_part2_matches_naive() {
+    for seed in 0..8 {
+        let mut rng = Rng::new(seed);
+        let input = (1..=3)
+            .map(|id| small_blueprint(&mut rng, id, 2))
+            .collect::<String>();
+        let params = d19::Params {
+            part2_minutes: rng.range(14, 16) as u32,
+            part2_blueprints: rng.range(1, 2) as usize,
+            ..d19::Params::default()
+        };
+        let blueprint_list = d19::parse(&input).unwrap();
+        let naive = blueprint_list[..params.part2_blueprints]
+            .iter()
+            .map(|blueprints| d19::naive_max_geodes(blueprints, params.part2_minutes))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            d19::solve_part2_with(&blueprint_list, &params),
+            naive.iter().product::<u32>(),
+            "seed {} with {:?}, naive {:?}\n{}",
+            seed,
+            params,
+            naive,
+            input
+        );
+    }
+}
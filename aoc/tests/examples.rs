@@ -0,0 +1,233 @@
+// every day is checked against the example from its puzzle description,
+// using the example files checked in next to each day
+mod common;
+
+use common::read_example;
+
+macro_rules! example {
+    ($(#[$attr:meta])* $name:ident, $file:literal, $solve:expr, $expected:literal) => {
+        $(#[$attr])*
+        #[test]
+        fn $name() {
+            let input = read_example($file);
+            assert_eq!($solve(&input).unwrap().to_string(), $expected);
+        }
+    };
+}
+
+example!(d1_part1, "2022/d1/test", d1::part1, "24000");
+example!(d1_part2, "2022/d1/test", d1::part2, "45000");
+example!(d2_part1, "2022/d2/test", d2::part1, "15");
+example!(d2_part2, "2022/d2/test", d2::part2, "12");
+example!(d3_part1, "2022/d3/test", d3::part1, "157");
+example!(d3_part2, "2022/d3/test", d3::part2, "70");
+example!(d4_part1, "2022/d4/test", d4::part1, "2");
+example!(d4_part2, "2022/d4/test", d4::part2, "4");
+example!(d5_part1, "2022/d5/test", d5::part1, "CMZ");
+example!(d5_part2, "2022/d5/test", d5::part2, "MCD");
+
+// not from the puzzle, ids from 10 on take two digits
+#[test]
+fn d5_two_digit_stack_ids() {
+    let mut input = (b'A'..=b'K')
+        .map(|c| format!("[{}]", c as char))
+        .collect::<Vec<_>>()
+        .join(" ");
+    input += "\n 1   2   3   4   5   6   7   8   9   10  11\n\nmove 1 from 10 to 1\n";
+    assert_eq!(d5::part1(&input).unwrap(), "JBCDEFGHIK");
+}
+example!(d6_part1, "2022/d6/test", d6::part1, "7");
+example!(d6_part2, "2022/d6/test", d6::part2, "19");
+
+// not from the puzzle, streams too repetitive for a marker
+#[test]
+fn d6_streams_without_a_marker() {
+    assert_eq!(
+        d6::part1("abcabc\n").unwrap_err().to_string(),
+        "line 1, column 7: expected a marker of 4 different characters"
+    );
+    assert_eq!(d6::part1("abcd\n").unwrap(), "4");
+    assert_eq!(
+        d6::part2("abcd\n").unwrap_err().to_string(),
+        "line 1, column 5: expected a marker of 14 different characters"
+    );
+}
+example!(d7_part1, "2022/d7/test", d7::part1, "95437");
+example!(d7_part2, "2022/d7/test", d7::part2, "24933642");
+example!(d8_part1, "2022/d8/test", d8::part1, "21");
+example!(d8_part2, "2022/d8/test", d8::part2, "8");
+example!(d9_part1, "2022/d9/test", d9::part1, "13");
+// the second example is the one with a longer rope to follow
+example!(d9_part2, "2022/d9/test1", d9::part2, "36");
+example!(d10_part1, "2022/d10/test", d10::part1, "13140");
+example!(
+    d10_part2,
+    "2022/d10/test",
+    d10::part2,
+    "##..##..##..##..##..##..##..##..##..##..\n\
+     ###...###...###...###...###...###...###.\n\
+     ####....####....####....####....####....\n\
+     #####.....#####.....#####.....#####.....\n\
+     ######......######......######......####\n\
+     #######.......#######.......#######....."
+);
+example!(d11_part1, "2022/d11/test", d11::part1, "10605");
+example!(d11_part2, "2022/d11/test", d11::part2, "2713310158");
+example!(d12_part1, "2022/d12/test", d12::part1, "31");
+example!(d12_part2, "2022/d12/test", d12::part2, "29");
+
+// not from the puzzle, E is too high to climb to
+#[test]
+fn d12_maps_without_a_path() {
+    assert_eq!(
+        d12::part1("SaE\n").unwrap_err().to_string(),
+        "line 1, column 3: expected a path from S to E"
+    );
+    assert_eq!(
+        d12::part2("SaE\n").unwrap_err().to_string(),
+        "line 1, column 3: expected a path from a square of elevation a to E"
+    );
+}
+example!(d13_part1, "2022/d13/test", d13::part1, "13");
+example!(d13_part2, "2022/d13/test", d13::part2, "140");
+example!(d14_part1, "2022/d14/test", d14::part1, "24");
+example!(d14_part2, "2022/d14/test", d14::part2, "93");
+// the example asks about row 10 and a search area of 20 instead of the
+// real puzzle's values
+const D15_EXAMPLE: d15::Params = d15::Params { row: 10, bound: 20 };
+example!(
+    d15_part1,
+    "2022/d15/test",
+    |input: &str| d15::parse(input).map(|list| d15::solve_part1_with(&list, &D15_EXAMPLE)),
+    "26"
+);
+example!(
+    d15_part2,
+    "2022/d15/test",
+    |input: &str| d15::parse(input).map(|list| d15::solve_part2_with(&list, &D15_EXAMPLE).unwrap()),
+    "56000011"
+);
+example!(d16_part1, "2022/d16/test", d16::part1, "1651");
+example!(d16_part2, "2022/d16/test", d16::part2, "1707");
+
+// not from the puzzle, a line of valves with a flow rate in every one, more
+// than one bit each fits in
+#[test]
+fn d16_too_many_flowing_valves() {
+    let names = (0..65)
+        .map(|index| match index {
+            0 => "AA".to_string(),
+            _ => format!("V{}", index),
+        })
+        .collect::<Vec<_>>();
+    let input = (0..65)
+        .map(|index| {
+            let next = &names[(index + 1) % 65];
+            format!(
+                "Valve {} has flow rate=1; tunnel leads to valve {}\n",
+                names[index], next
+            )
+        })
+        .collect::<String>();
+    assert_eq!(
+        d16::parse(&input).err().unwrap().to_string(),
+        "line 65, column 25: expected at most 64 valves with a flow rate"
+    );
+    let fits = input.replacen("rate=1", "rate=0", 1);
+    assert!(d16::parse(&fits).is_ok());
+}
+example!(d17_part1, "2022/d17/test", d17::part1, "3068");
+example!(d17_part2, "2022/d17/test", d17::part2, "1514285714288");
+example!(d18_part1, "2022/d18/test", d18::part1, "64");
+example!(d18_part2, "2022/d18/test", d18::part2, "58");
+// the checked in example repeats the second blueprint as a third one, so
+// the answers differ from the puzzle description
+example!(
+    #[ignore = "slow, run with --release -- --ignored"]
+    d19_part1,
+    "2022/d19/test",
+    d19::part1,
+    "69"
+);
+example!(
+    #[ignore = "slow, run with --release -- --ignored"]
+    d19_part2,
+    "2022/d19/test",
+    d19::part2,
+    "215264"
+);
+example!(d20_part1, "2022/d20/test", d20::part1, "3");
+example!(d20_part2, "2022/d20/test", d20::part2, "1623178306");
+example!(d21_part1, "2022/d21/test", d21::part1, "152");
+example!(d21_part2, "2022/d21/test", d21::part2, "301");
+example!(d22_part1, "2022/d22/test", d22::part1, "6032");
+example!(d22_part2, "2022/d22/test", d22::part2, "5031");
+
+// not from the puzzle, boards that only part two cannot walk
+#[test]
+fn d22_boards_that_are_no_cube() {
+    let error = |input: &str| d22::part2(input).unwrap_err().to_string();
+
+    assert_eq!(d22::part1("..\n\n2\n").unwrap(), "1004");
+    assert_eq!(
+        error("..\n\n2\n"),
+        "line 1, column 1: expected a cube net of six square faces"
+    );
+    // six faces in a row wrap around onto the first one
+    assert_eq!(
+        error("......\n\n2\n"),
+        "line 1, column 5: expected a cube net"
+    );
+    // the right number of tiles, but not on whole faces
+    assert_eq!(
+        error("............\n ............\n\n2\n"),
+        "line 2, column 1: expected a face of 2 by 2 tiles"
+    );
+}
+example!(d23_part1, "2022/d23/test.txt", d23::part1, "110");
+example!(d23_part2, "2022/d23/test.txt", d23::part2, "20");
+example!(d24_part1, "2022/d24/input.txt", d24::part1, "18");
+example!(d24_part2, "2022/d24/input.txt", d24::part2, "54");
+
+// not from the puzzle, valleys with no way in, out or through
+#[test]
+fn d24_valleys_without_a_way_through() {
+    let error = |input: &str| d24::part1(input).unwrap_err().to_string();
+    assert_eq!(
+        error("#.###\n#...#\n#####\n"),
+        "line 3, column 4: expected an exit"
+    );
+    assert_eq!(
+        error("###.#\n#...#\n###.#\n"),
+        "line 1, column 2: expected an entrance"
+    );
+    assert_eq!(error("#\n"), "line 1, column 2: expected an entrance");
+    assert_eq!(
+        error("#.###\n#####\n###.#\n"),
+        "line 3, column 4: expected a way through the blizzards"
+    );
+}
+example!(day1_part1, "2023/day1/example1.txt", day1::part1, "142");
+example!(day1_part2, "2023/day1/example.txt", day1::part2, "281");
+example!(day2_part1, "2023/day2/example.txt", day2::part1, "8");
+example!(day2_part2, "2023/day2/example.txt", day2::part2, "2286");
+// a single game that needs 14 green cubes, one more than the bag holds
+example!(day2_no_game_part1, "2023/day2/test.txt", day2::part1, "0");
+example!(day2_no_game_part2, "2023/day2/test.txt", day2::part2, "14");
+example!(day3_part1, "2023/day3/test.txt", day3::part1, "4361");
+example!(day3_part2, "2023/day3/test.txt", day3::part2, "467835");
+
+// not from the puzzle, answers past 32 bits and a number that does not fit
+#[test]
+fn day2_and_day3_large_numbers() {
+    let game = "Game 1: 4000000000 red, 4000000000 green, 4000000000 blue\n";
+    assert_eq!(day2::part2(game).unwrap(), "64000000000000000000000000000");
+    assert_eq!(
+        day3::part2("4000000000*4000000000\n").unwrap(),
+        "16000000000000000000"
+    );
+    assert_eq!(
+        day3::parse("..49999999999967\n").err().unwrap().to_string(),
+        "line 1, column 3: expected a number that fits in 32 bits"
+    );
+}
@@ -0,0 +1,381 @@
+// the filesystem d7 rebuilds from shell transcripts
+mod common;
+
+use aoc_common::ParseError;
+use common::read_example;
+
+fn answers(input: &str) -> (u64, u64) {
+    let graph = d7::parse(input).unwrap();
+    (d7::solve_part1(&graph), d7::solve_part2(&graph).unwrap())
+}
+
+#[test]
+fn paths_and_repeated_listings() {
+    // the example again, moving around with absolute and relative paths
+    // and listing some directories twice
+    let input = "\
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a/
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd /a/e
+$ ls
+584 i
+$ cd ../../d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd ./a/./e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd ..
+";
+    assert_eq!(answers(input), answers(&read_example("2022/d7/test")));
+}
+
+#[test]
+fn missing_directories_are_pointed_at() {
+    let input = "$ cd /\n$ ls\ndir a\n$ cd a/b\n";
+    assert_eq!(
+        d7::parse(input).err(),
+        Some(ParseError::new(4, 8, "a directory of /a"))
+    );
+    // files are not directories either
+    let input = "$ ls\n12 a.txt\n$ cd a.txt\n";
+    assert_eq!(
+        d7::parse(input).err(),
+        Some(ParseError::new(3, 6, "a directory of /"))
+    );
+}
+
+#[test]
+fn listings_only_follow_ls() {
+    assert_eq!(
+        d7::parse("$ cd /\n12 a.txt\n").err(),
+        Some(ParseError::new(2, 1, "a command such as $ ls"))
+    );
+    assert_eq!(
+        d7::parse("$ ls\ndir a\n3 a\n").err(),
+        Some(ParseError::new(3, 3, "the same kind of entry as before"))
+    );
+}
+
+#[test]
+fn directory_size_queries() {
+    let graph = d7::parse(&read_example("2022/d7/test")).unwrap();
+    let sizes = graph.directory_sizes();
+    assert_eq!(sizes.total(), 48381165);
+    assert_eq!(sizes.sum_at_most(100000), 95437);
+    assert_eq!(sizes.sum_at_most(0), 0);
+    assert_eq!(sizes.smallest_at_least(8381165), Some(24933642));
+    assert_eq!(sizes.smallest_at_least(584), Some(584));
+    assert_eq!(sizes.smallest_at_least(48381166), None);
+    assert_eq!(
+        sizes.largest(3),
+        vec![
+            ("/".to_string(), 48381165),
+            ("/d".to_string(), 24933642),
+            ("/a".to_string(), 94853)
+        ]
+    );
+    assert_eq!(sizes.largest(10).len(), 4);
+}
+
+#[test]
+fn sizes_can_be_asked_for_again() {
+    let input = read_example("2022/d7/test");
+    let graph = d7::parse(&input).unwrap();
+    let first = answers(&input);
+    assert_eq!(answers(&input), first);
+    assert_eq!(
+        graph.directory_sizes().largest(4),
+        graph.directory_sizes().largest(4)
+    );
+}
+
+#[test]
+fn tree_like_the_puzzle() {
+    let graph = d7::parse(&read_example("2022/d7/test")).unwrap();
+    let expected = "\
+- / (dir, size=48381165)
+  - a (dir, size=94853)
+    - e (dir, size=584)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir, size=24933642)
+    - d.ext (file, size=5626152)
+    - d.log (file, size=8033020)
+    - j (file, size=4060174)
+    - k (file, size=7214296)
+";
+    assert_eq!(d7::tree(&graph, &d7::ReportOptions::default()), expected);
+
+    let options = d7::ReportOptions {
+        sort: d7::SortBy::Size,
+        max_depth: Some(1),
+        human: true,
+    };
+    let expected = "\
+- / (dir, size=46.1M)
+  - d (dir, size=23.8M)
+  - b.txt (file, size=14.2M)
+  - c.dat (file, size=8.1M)
+  - a (dir, size=92.6K)
+";
+    assert_eq!(d7::tree(&graph, &options), expected);
+}
+
+#[test]
+fn du_lists_every_directory() {
+    let graph = d7::parse(&read_example("2022/d7/test")).unwrap();
+    assert_eq!(
+        d7::du(&graph, &d7::ReportOptions::default()),
+        "48381165\t/\n94853\t/a\n584\t/a/e\n24933642\t/d\n"
+    );
+    let options = d7::ReportOptions {
+        sort: d7::SortBy::Size,
+        max_depth: Some(1),
+        human: false,
+    };
+    assert_eq!(
+        d7::du(&graph, &options),
+        "48381165\t/\n24933642\t/d\n94853\t/a\n"
+    );
+}
+
+#[test]
+fn human_sizes() {
+    assert_eq!(d7::format_size(1023, true), "1023");
+    assert_eq!(d7::format_size(1024, true), "1.0K");
+    assert_eq!(d7::format_size(5 << 30, true), "5.0G");
+    assert_eq!(d7::format_size(5 << 30, false), "5368709120");
+}
+
+#[test]
+fn json_nests_the_entries() {
+    let graph = d7::parse(&read_example("2022/d7/test")).unwrap();
+    let root = d7::entries(&graph, &d7::ReportOptions::default());
+    assert_eq!((root.name.as_str(), root.size), ("/", 48381165));
+    let e = &root.children[0].children[0];
+    assert_eq!((e.name.as_str(), e.size, e.is_directory), ("e", 584, true));
+    assert_eq!(e.children[0].name, "i");
+    assert!(!e.children[0].is_directory && e.children[0].children.is_empty());
+
+    let json: serde_json::Value =
+        serde_json::from_str(&d7::json(&graph, &d7::ReportOptions::default())).unwrap();
+    assert_eq!(json["children"][3]["name"], "d");
+    assert_eq!(json["children"][3]["size"], 24933642);
+    assert_eq!(json["children"][3]["is_directory"], true);
+    assert_eq!(json["children"][3]["children"].as_array().unwrap().len(), 4);
+
+    let options = d7::ReportOptions {
+        max_depth: Some(0),
+        ..Default::default()
+    };
+    assert!(d7::entries(&graph, &options).children.is_empty());
+}
+
+#[test]
+fn dot_labels_directories_with_their_size() {
+    let input = "\
+$ cd /
+$ ls
+dir a
+12 say \"hi\".txt
+$ cd a
+$ ls
+30 b
+";
+    let graph = d7::parse(input).unwrap();
+    let dot = d7::dot(&graph, &d7::ReportOptions::default());
+    assert!(dot.starts_with("digraph filesystem {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("[label=\"/\\n42\", shape=box];"));
+    assert!(dot.contains("[label=\"a\\n30\", shape=box];"));
+    assert!(dot.contains("[label=\"say \\\"hi\\\".txt\\n12\", shape=plaintext];"));
+    assert_eq!(dot.matches(" -> ").count(), 3);
+}
+
+fn paths(deletions: &[d7::Deletion]) -> Vec<&str> {
+    deletions
+        .iter()
+        .map(|deletion| deletion.path.as_str())
+        .collect()
+}
+
+#[test]
+fn plan_like_part_two() {
+    let graph = d7::parse(&read_example("2022/d7/test")).unwrap();
+    let plan = d7::plan(&graph, &d7::Params::default());
+    assert_eq!(
+        (plan.used, plan.free(), plan.needed),
+        (48381165, 21618835, 8381165)
+    );
+    let smallest = plan.smallest.clone().unwrap();
+    assert_eq!((smallest.path.as_str(), smallest.size), ("/d", 24933642));
+    assert_eq!(plan.free_after(&[smallest]), 46552477);
+    assert_eq!(paths(plan.set.as_ref().unwrap()), ["/d"]);
+
+    let report = d7::plan_report(&plan, false);
+    assert!(report.contains("smallest directory: /d (24933642), leaving 46552477 free\n"));
+    assert!(report.contains("  24933642\t/d\n"));
+}
+
+#[test]
+fn plan_a_set_of_directories() {
+    let input = "\
+$ ls
+dir x
+dir y
+dir z
+$ cd x
+$ ls
+5 a
+$ cd ../y
+$ ls
+5 b
+$ cd ../z
+$ ls
+12 c
+";
+    let graph = d7::parse(input).unwrap();
+    let params = d7::Params {
+        disk_size: 30,
+        required_space: 18,
+        ..Default::default()
+    };
+    let plan = d7::plan(&graph, &params);
+    assert_eq!((plan.free(), plan.needed), (8, 10));
+    assert_eq!(plan.smallest.as_ref().unwrap().path, "/z");
+    let set = plan.set.clone().unwrap();
+    assert_eq!(paths(&set), ["/x", "/y"]);
+    assert!(plan.proven);
+    assert_eq!(plan.free_after(&set), 18);
+}
+
+#[test]
+fn plan_never_counts_a_directory_twice() {
+    // a and b together would free exactly 13, but b is inside a
+    let input = "\
+$ ls
+dir a
+dir c
+$ cd a
+$ ls
+1 file
+dir b
+$ cd b
+$ ls
+6 file
+$ cd /c
+$ ls
+8 file
+";
+    let graph = d7::parse(input).unwrap();
+    let params = d7::Params {
+        disk_size: 20,
+        required_space: 18,
+        ..Default::default()
+    };
+    let plan = d7::plan(&graph, &params);
+    assert_eq!(plan.needed, 13);
+    assert_eq!(paths(plan.set.as_ref().unwrap()), ["/a/b", "/c"]);
+    assert_eq!(plan.smallest.unwrap().path, "/");
+}
+
+#[test]
+fn plan_with_gigabyte_files() {
+    // thirty directories of about 3G each, d0 the smallest
+    let mut input = String::from("$ ls\n");
+    for index in 0..30 {
+        input += &format!("dir d{index}\n");
+    }
+    for index in 0..30 {
+        input += &format!(
+            "$ cd /d{index}\n$ ls\n{} f\n",
+            3_000_000_000u64 + index * 1_000_000
+        );
+    }
+    let graph = d7::parse(&input).unwrap();
+    let params = d7::Params {
+        disk_size: 100_000_000_000,
+        required_space: 21_571_000_000,
+        ..Default::default()
+    };
+    let plan = d7::plan(&graph, &params);
+    assert_eq!((plan.used, plan.needed), (90_435_000_000, 12_006_000_000));
+    assert_eq!(plan.smallest.as_ref().unwrap().path, "/");
+    let set = plan.set.clone().unwrap();
+    assert_eq!(paths(&set), ["/d0", "/d1", "/d2", "/d3"]);
+    assert_eq!(plan.free_after(&set), 21_571_000_000);
+}
+
+// sixty directories of even sizes close to each other and an odd amount to
+// free, so no set frees exactly that and the search cannot stop early
+#[test]
+fn plan_gives_up_on_many_similar_directories() {
+    let mut input = String::from("$ ls\n");
+    for index in 0..60 {
+        input += &format!("dir d{index}\n");
+    }
+    for index in 0..60u64 {
+        let size = 2 * (500_000 + index * 7919 % 500_000);
+        input += &format!("$ cd /d{index}\n$ ls\n{size} f\n");
+    }
+    let graph = d7::parse(&input).unwrap();
+    let params = d7::Params {
+        disk_size: 100_000_000,
+        required_space: 77_000_001,
+        ..Default::default()
+    };
+    let plan = d7::plan(&graph, &params);
+    assert!(plan.needed % 2 == 1 && !plan.proven);
+    let set = plan.set.clone().unwrap();
+    let freed = set.iter().map(|deletion| deletion.size).sum::<u64>();
+    assert!(freed > plan.needed && freed <= plan.smallest.as_ref().unwrap().size);
+    assert!(plan.free_after(&set) > 77_000_001);
+    let report = d7::plan_report(&plan, false);
+    assert!(report.contains("best set found: "));
+    assert!(report.contains("a smaller set may exist"));
+}
+
+#[test]
+fn plan_when_nothing_or_everything_is_not_enough() {
+    let graph = d7::parse(&read_example("2022/d7/test")).unwrap();
+    let params = d7::Params {
+        required_space: 10000000,
+        ..Default::default()
+    };
+    let plan = d7::plan(&graph, &params);
+    assert_eq!(plan.needed, 0);
+    assert_eq!((plan.smallest, plan.set), (None, Some(Vec::new())));
+
+    let params = d7::Params {
+        required_space: 80000000,
+        ..Default::default()
+    };
+    let plan = d7::plan(&graph, &params);
+    assert_eq!((plan.smallest.clone(), plan.set.clone()), (None, None));
+    assert!(d7::plan_report(&plan, false).contains("no directory frees up enough space"));
+}
@@ -0,0 +1,128 @@
+// every generator has to produce input its own day accepts and solves, and
+// the same input again for the same seed
+use aoc_common::Rng;
+
+macro_rules! generated {
+    ($name:ident, $day:ident) => {
+        #[test]
+        fn $name() {
+            for seed in 0..10 {
+                for size in [1, 10, 40] {
+                    let input = $day::generate(&mut Rng::new(seed), size);
+                    if let Err(err) = $day::parse(&input) {
+                        panic!("seed {} size {}: {}\n{}", seed, size, err, input);
+                    }
+                    assert_eq!(input, $day::generate(&mut Rng::new(seed), size));
+                }
+            }
+        }
+    };
+}
+
+generated!(d1_parses, d1);
+generated!(d2_parses, d2);
+generated!(d3_parses, d3);
+generated!(d4_parses, d4);
+generated!(d5_parses, d5);
+generated!(d6_parses, d6);
+generated!(d7_parses, d7);
+generated!(d8_parses, d8);
+generated!(d9_parses, d9);
+generated!(d10_parses, d10);
+generated!(d11_parses, d11);
+generated!(d12_parses, d12);
+generated!(d13_parses, d13);
+generated!(d14_parses, d14);
+generated!(d15_parses, d15);
+generated!(d16_parses, d16);
+generated!(d17_parses, d17);
+generated!(d18_parses, d18);
+generated!(d19_parses, d19);
+generated!(d20_parses, d20);
+generated!(d21_parses, d21);
+generated!(d22_parses, d22);
+generated!(d23_parses, d23);
+generated!(d24_parses, d24);
+generated!(day1_parses, day1);
+generated!(day2_parses, day2);
+generated!(day3_parses, day3);
+
+// both parts on the inputs of some seeds, at the sizes given or the ones
+// above. a solver can also be given for days whose parts take too long on
+// inputs the size of a real one
+macro_rules! solved {
+    ($name:ident, $day:ident) => {
+        solved!($name, $day, [1, 10, 40]);
+    };
+    ($name:ident, $day:ident, $sizes:expr) => {
+        solved!($name, $day, $sizes, |input: &str| {
+            $day::part1(input).map_err(|err| err.to_string())?;
+            $day::part2(input).map_err(|err| err.to_string())?;
+            Ok(())
+        });
+    };
+    ($name:ident, $day:ident, $sizes:expr, $solve:expr) => {
+        #[test]
+        fn $name() {
+            let solve: fn(&str) -> Result<(), String> = $solve;
+            for seed in 0..3 {
+                for size in $sizes {
+                    let input = $day::generate(&mut Rng::new(seed), size);
+                    if let Err(err) = solve(&input) {
+                        panic!("seed {} size {}: {}\n{}", seed, size, err, input);
+                    }
+                }
+            }
+        }
+    };
+}
+
+solved!(d1_solves, d1);
+solved!(d2_solves, d2);
+solved!(d3_solves, d3);
+solved!(d4_solves, d4);
+solved!(d5_solves, d5);
+solved!(d6_solves, d6);
+solved!(d7_solves, d7);
+solved!(d8_solves, d8);
+solved!(d9_solves, d9);
+solved!(d10_solves, d10);
+solved!(d11_solves, d11);
+solved!(d12_solves, d12);
+solved!(d13_solves, d13);
+solved!(d14_solves, d14);
+// d15 is left out, its parts go through hundreds of thousands of rows of
+// a real sized area, which differential.rs checks on small areas instead
+// past 64 valves, where a valve per bit no longer fits
+solved!(d16_solves, d16, [1, 10, 40, 100]);
+solved!(d17_solves, d17);
+solved!(d18_solves, d18);
+// 32 minutes take too long on every blueprint
+solved!(d19_solves, d19, [1, 10, 40], |input: &str| {
+    let blueprint_list = d19::parse(input).map_err(|err| err.to_string())?;
+    let params = d19::Params {
+        part1_minutes: 20,
+        part2_minutes: 20,
+        ..d19::Params::default()
+    };
+    d19::solve_part1_with(&blueprint_list, &params);
+    d19::solve_part2_with(&blueprint_list, &params);
+    Ok(())
+});
+solved!(d20_solves, d20);
+solved!(d21_solves, d21);
+solved!(d22_solves, d22);
+solved!(d23_solves, d23);
+solved!(d24_solves, d24);
+solved!(day1_solves, day1);
+solved!(day2_solves, day2);
+solved!(day3_solves, day3);
+
+// larger sizes are capped at the width of a real valley, which is as much
+// as the solver has memory for
+#[test]
+fn d24_generates_at_most_a_real_valley() {
+    let input = d24::generate(&mut Rng::new(0), 2000);
+    assert_eq!(input.lines().next().unwrap().len(), 122);
+    assert_eq!(input.lines().count(), 42);
+}
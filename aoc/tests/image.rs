@@ -0,0 +1,47 @@
+// the frames of the replays as pictures
+use aoc_common::{colour, Frame, Image};
+
+fn small_frame() -> Frame {
+    let mut frame = Frame::new();
+    frame.set((-1, 2), '#');
+    frame.set((1, 3), 'o');
+    frame
+}
+
+#[test]
+fn cells_become_squares() {
+    let image = Image::from_frame(&small_frame(), 3);
+    assert_eq!((image.width(), image.height()), (9, 6));
+    for y in 0..3 {
+        for x in 0..3 {
+            assert_eq!(image.pixel(x, y), colour('#'));
+            assert_eq!(image.pixel(x + 6, y + 3), colour('o'));
+            assert_eq!(image.pixel(x + 3, y), colour(' '));
+        }
+    }
+}
+
+#[test]
+fn ppm_is_header_and_pixels() {
+    let image = Image::from_frame(&small_frame(), 1);
+    let mut ppm = Vec::new();
+    image.write_ppm(&mut ppm).unwrap();
+    let header = b"P6\n3 2\n255\n";
+    assert_eq!(&ppm[..header.len()], header);
+    assert_eq!(ppm.len(), header.len() + 3 * 2 * 3);
+    assert_eq!(ppm[header.len()..header.len() + 3], colour('#'));
+}
+
+#[test]
+fn png_has_its_signature() {
+    let image = Image::from_frame(&small_frame(), 2);
+    let mut png = Vec::new();
+    image.write_png(&mut png).unwrap();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+}
+
+#[test]
+fn unknown_formats_are_refused() {
+    let path = std::env::temp_dir().join("aoc-image-test.gif");
+    assert!(Image::from_frame(&small_frame(), 1).save(&path).is_err());
+}
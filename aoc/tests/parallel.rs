@@ -0,0 +1,51 @@
+// the days that split their work over the thread pool have to come up with
+// the same answers whatever the number of threads
+mod common;
+
+use aoc_common::Rng;
+use common::read_example;
+use rayon::ThreadPoolBuilder;
+
+fn with_threads<R: Send>(threads: usize, work: impl FnOnce() -> R + Send) -> R {
+    ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap()
+        .install(work)
+}
+
+// the binary of a day or another crate calling it never asked for threads
+#[test]
+fn one_thread_unless_on_a_pool() {
+    assert_eq!(aoc_common::parallel(rayon::current_num_threads), 1);
+    assert_eq!(
+        with_threads(3, || aoc_common::parallel(rayon::current_num_threads)),
+        3
+    );
+}
+
+#[test]
+fn d8_same_on_any_pool() {
+    for seed in 0..20 {
+        let input = d8::generate(&mut Rng::new(seed), 30);
+        let forest = d8::parse(&input).unwrap();
+        assert_eq!(
+            with_threads(1, || d8::solve_part2(&forest)),
+            with_threads(4, || d8::solve_part2(&forest)),
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn d15_same_on_any_pool() {
+    let input = read_example("2022/d15/test");
+    let sensor_list = d15::parse(&input).unwrap();
+    for threads in [1, 2, 4] {
+        assert_eq!(
+            with_threads(threads, || d15::solution_2(&sensor_list, 20)),
+            Some(56000011)
+        );
+    }
+}
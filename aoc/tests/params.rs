@@ -0,0 +1,163 @@
+// the puzzle parameters of the days, as they would come from a parameters
+// file or the command line
+mod common;
+
+use std::process::Command;
+
+use common::{read_example, repo_root};
+
+#[test]
+fn missing_fields_keep_their_default() {
+    let params: d15::Params = toml::from_str("row = 10").unwrap();
+    assert_eq!(
+        params,
+        d15::Params {
+            row: 10,
+            ..d15::Params::default()
+        }
+    );
+    assert_eq!(
+        toml::from_str::<d19::Params>("").unwrap(),
+        d19::Params::default()
+    );
+}
+
+#[test]
+fn unknown_fields_are_refused() {
+    assert!(toml::from_str::<d16::Params>("minute = 20").is_err());
+    assert!(toml::from_str::<d17::Params>("part1_rocks = \"many\"").is_err());
+}
+
+#[test]
+fn defaults_are_the_puzzle() {
+    let input = read_example("2022/d11/test");
+    let game = d11::parse(&input).unwrap();
+    assert_eq!(
+        d11::solve_part2_with(&game, &d11::Params::default()).to_string(),
+        d11::part2(&input).unwrap()
+    );
+}
+
+#[test]
+fn d7_smaller_disk() {
+    let graph = d7::parse(&read_example("2022/d7/test")).unwrap();
+    let params = d7::Params {
+        small_size: 1000,
+        disk_size: 48381165,
+        required_space: 600,
+    };
+    // only e is small enough, but too small to free the 600 of a full disk
+    assert_eq!(d7::solve_part1_with(&graph, &params), 584);
+    assert_eq!(d7::solve_part2_with(&graph, &params), Ok(94853));
+}
+
+#[test]
+fn d11_twenty_rounds_without_relief() {
+    let game = d11::parse(&read_example("2022/d11/test")).unwrap();
+    let params = d11::Params {
+        part2_rounds: 20,
+        ..d11::Params::default()
+    };
+    // 99 and 103 inspections by the two busiest monkeys, from the puzzle text
+    assert_eq!(d11::solve_part2_with(&game, &params), 99 * 103);
+}
+
+#[test]
+fn d17_rock_counts() {
+    let jetstream = d17::parse(&read_example("2022/d17/test")).unwrap();
+    let params = d17::Params {
+        part1_rocks: 10,
+        part2_rocks: 2022,
+    };
+    // the tower of the puzzle text is 17 high after 10 rocks
+    assert_eq!(d17::solve_part1_with(&jetstream, &params), 17);
+    assert_eq!(d17::solve_part2_with(&jetstream, &params), 3068);
+}
+
+#[test]
+fn d20_one_round_without_key_is_part_one() {
+    let list = d20::parse(&read_example("2022/d20/test")).unwrap();
+    let params = d20::Params {
+        decryption_key: 1,
+        rounds: 1,
+    };
+    assert_eq!(d20::solve_part2_with(&list, &params), 3);
+}
+
+#[test]
+fn d7_disk_too_small_for_the_space_required() {
+    let graph = d7::parse(&read_example("2022/d7/test")).unwrap();
+    let params = d7::Params {
+        required_space: 80000000,
+        ..d7::Params::default()
+    };
+    assert_eq!(
+        d7::solve_part2_with(&graph, &params),
+        Err("no directory frees up 58381165 of the disk of 70000000".to_string())
+    );
+}
+
+#[test]
+fn d15_bound_without_a_gap() {
+    let sensor_list = d15::parse(&read_example("2022/d15/test")).unwrap();
+    let params = d15::Params { row: 10, bound: 5 };
+    assert_eq!(
+        d15::solve_part2_with(&sensor_list, &params),
+        Err("no position within 0..=5 is left uncovered".to_string())
+    );
+}
+
+// the same through aoc run, which has to say so rather than panic
+#[test]
+fn run_reports_parameters_without_an_answer() {
+    for (day, example, param, message) in [
+        (
+            "7",
+            "2022/d7/test",
+            "required_space=80000000",
+            "2022 day 7: invalid parameters: no directory frees up 58381165 of the disk of 70000000",
+        ),
+        (
+            "15",
+            "2022/d15/test",
+            "bound=5",
+            "2022 day 15: invalid parameters: no position within 0..=5 is left uncovered",
+        ),
+    ] {
+        let root = repo_root();
+        let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .current_dir(&root)
+            .args(["run", "--year", "2022", "--day", day, "--part", "2"])
+            .args(["--param", param, "--input", example])
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(1));
+        assert_eq!(String::from_utf8_lossy(&output.stderr).trim(), message);
+    }
+}
+
+// one sensor that sees the whole search area of the puzzle, so not even the
+// default parameters have an answer and aoc run has no --param to blame
+#[test]
+fn default_parameters_without_an_answer() {
+    let input = "Sensor at x=2000000, y=2000000: closest beacon is at x=2000000, y=6000001\n";
+    let sensor_list = d15::parse(input).unwrap();
+    let message = "no position within 0..=4000000 is left uncovered";
+    assert_eq!(d15::solve_part2(&sensor_list), Err(message.to_string()));
+    assert_eq!(d15::part2(input).unwrap_err().to_string(), message);
+
+    let path = std::env::temp_dir().join("aoc-params-covered.txt");
+    std::fs::write(&path, input).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "run", "--year", "2022", "--day", "15", "--part", "2", "--input",
+        ])
+        .arg(&path)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr).trim(),
+        format!("2022 day 15: invalid parameters: {}", message)
+    );
+}
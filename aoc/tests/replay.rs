@@ -0,0 +1,220 @@
+// the replays of the simulation days have to tell the same story as the
+// answers, frame by frame
+mod common;
+
+use aoc_common::{Frame, FrameSink};
+use common::read_example;
+
+fn count(frame: &Frame, c: char) -> usize {
+    frame.cells().filter(|&(_, cell)| cell == c).count()
+}
+
+// turns down every step, like an image of the final state
+#[derive(Default)]
+struct FinalOnly(Vec<Frame>);
+
+impl FrameSink for FinalOnly {
+    fn wants_frame(&mut self) -> bool {
+        false
+    }
+
+    fn final_only(&self) -> bool {
+        true
+    }
+
+    fn frame(&mut self, frame: &Frame) {
+        self.0.push(frame.clone());
+    }
+}
+
+#[test]
+fn d8_visibility_map() {
+    let forest = d8::parse(&read_example("2022/d8/test")).unwrap();
+    let mut frames = Vec::<Frame>::new();
+    d8::replay(&forest, 1, &mut frames);
+    assert_eq!(frames.len(), 1);
+    assert_eq!(count(&frames[0], '#'), 21);
+
+    let mut frames = Vec::<Frame>::new();
+    d8::replay(&forest, 2, &mut frames);
+    assert_eq!(frames[0].get((2, 3)), Some('@'));
+    assert_eq!(frames[0].caption(), "best view from 2,3: 8");
+}
+
+#[test]
+fn d9_one_frame_per_step() {
+    for (file, part) in [("2022/d9/test", 1), ("2022/d9/test1", 2)] {
+        let motions = d9::parse(&read_example(file)).unwrap();
+        let mut frames = Vec::<Frame>::new();
+        d9::replay(&motions, part, &mut frames);
+        let steps = motions.iter().map(|&(_, distance)| distance).sum::<i32>();
+        assert_eq!(frames.len(), steps as usize);
+        // the camera follows the head
+        for frame in &frames {
+            assert_eq!(frame.get(frame.focus().unwrap()), Some('H'));
+        }
+    }
+}
+
+#[test]
+fn d10_draws_the_crt() {
+    let input = read_example("2022/d10/test");
+    let program = d10::parse(&input).unwrap();
+    let mut frames = Vec::<Frame>::new();
+    d10::replay(&program, 2, &mut frames);
+    assert_eq!(frames.len(), 240);
+    assert_eq!(
+        frames.last().unwrap().render('.').trim_end(),
+        d10::part2(&input).unwrap()
+    );
+}
+
+#[test]
+fn d14_one_frame_per_grain() {
+    let game = d14::parse(&read_example("2022/d14/test")).unwrap();
+    for (part, grains) in [(1, 24), (2, 93)] {
+        let mut frames = Vec::<Frame>::new();
+        d14::replay(&game, part, &mut frames);
+        assert_eq!(frames.len(), grains);
+        assert_eq!(count(frames.last().unwrap(), 'o'), grains);
+        assert_eq!(
+            frames.last().unwrap().caption(),
+            format!("sand at rest: {}", grains)
+        );
+    }
+}
+
+#[test]
+fn d17_last_rock_lands_on_the_tower() {
+    let jetstream = d17::parse(&read_example("2022/d17/test")).unwrap();
+    let mut frames = Vec::<Frame>::new();
+    d17::replay(&jetstream, 1, &mut frames);
+    let last = &frames[frames.len() - 2];
+    assert!(
+        last.caption().starts_with("rock 2022,"),
+        "{}",
+        last.caption()
+    );
+    // the plus, falling onto a tower that ends up 3068 high
+    assert_eq!(count(last, '@'), 5);
+    let ((_, top), _) = last.bounds().unwrap();
+    assert!((3068 - 3..=3068).contains(&-top), "top {}", top);
+}
+
+#[test]
+fn d17_ends_with_the_whole_tower() {
+    let jetstream = d17::parse(&read_example("2022/d17/test")).unwrap();
+    let mut frames = Vec::<Frame>::new();
+    d17::replay(&jetstream, 1, &mut frames);
+    let tower = frames.last().unwrap();
+    assert_eq!(tower.caption(), "2022 rocks, tower height 3068");
+    assert_eq!(count(tower, '@'), 0);
+    // from the floor up to the top of the tower
+    assert_eq!(tower.bounds(), Some(((-1, -3067), (7, 1))));
+    // the five shapes are 22 cells together, the two left over a bar and
+    // a plus
+    assert_eq!(count(tower, '#'), 2022 / 5 * 22 + 4 + 5);
+}
+
+#[test]
+fn d18_one_frame_per_slice() {
+    let cube_set = d18::parse(&read_example("2022/d18/test")).unwrap();
+    for (part, pockets) in [(1, 0), (2, 1)] {
+        let mut frames = Vec::<Frame>::new();
+        d18::replay(&cube_set, part, &mut frames);
+        // and one with all of them
+        assert_eq!(frames.len(), 7);
+        let slices = &frames[..6];
+        let total = |c| slices.iter().map(|frame| count(frame, c)).sum::<usize>();
+        assert_eq!(total('#'), 13);
+        assert_eq!(total('~'), pockets);
+
+        let droplet = &frames[6];
+        assert_eq!(droplet.caption(), "z = 1 to 6");
+        assert_eq!(count(droplet, '#'), 13);
+        assert_eq!(count(droplet, '~'), pockets);
+    }
+}
+
+#[test]
+fn d23_one_frame_per_round() {
+    let scan = d23::parse(&read_example("2022/d23/test.txt")).unwrap();
+    let mut frames = Vec::<Frame>::new();
+    d23::replay(&scan, 1, &mut frames);
+    // the scan and ten rounds
+    assert_eq!(frames.len(), 11);
+    let last = frames.last().unwrap();
+    let ((min_x, min_y), (max_x, max_y)) = last.bounds().unwrap();
+    let area = (max_x - min_x + 1) * (max_y - min_y + 1);
+    assert_eq!(area as usize - count(last, '#'), 110);
+
+    let mut frames = Vec::<Frame>::new();
+    d23::replay(&scan, 2, &mut frames);
+    assert_eq!(frames.last().unwrap().caption(), "round 20");
+}
+
+#[test]
+fn d24_one_frame_per_minute() {
+    let game = d24::parse(&read_example("2022/d24/input.txt")).unwrap();
+    for (part, minutes) in [(1, 18), (2, 54)] {
+        let mut frames = Vec::<Frame>::new();
+        d24::replay(&game, part, &mut frames);
+        assert_eq!(frames.len(), minutes + 1);
+        assert!(frames
+            .last()
+            .unwrap()
+            .caption()
+            .starts_with(&format!("minute {},", minutes)));
+    }
+}
+
+#[test]
+fn final_only_sinks_get_the_last_frame_alone() {
+    type Replay = Box<dyn Fn(u8, &mut dyn FrameSink)>;
+    let motions = d9::parse(&read_example("2022/d9/test1")).unwrap();
+    let program = d10::parse(&read_example("2022/d10/test")).unwrap();
+    let game = d14::parse(&read_example("2022/d14/test")).unwrap();
+    let jetstream = d17::parse(&read_example("2022/d17/test")).unwrap();
+    let cube_set = d18::parse(&read_example("2022/d18/test")).unwrap();
+    let scan = d23::parse(&read_example("2022/d23/test.txt")).unwrap();
+    let valley = d24::parse(&read_example("2022/d24/input.txt")).unwrap();
+    let replays: Vec<(&str, Replay)> = vec![
+        (
+            "d9",
+            Box::new(move |part, sink| d9::replay(&motions, part, sink)),
+        ),
+        (
+            "d10",
+            Box::new(move |part, sink| d10::replay(&program, part, sink)),
+        ),
+        (
+            "d14",
+            Box::new(move |part, sink| d14::replay(&game, part, sink)),
+        ),
+        (
+            "d17",
+            Box::new(move |part, sink| d17::replay(&jetstream, part, sink)),
+        ),
+        (
+            "d18",
+            Box::new(move |part, sink| d18::replay(&cube_set, part, sink)),
+        ),
+        (
+            "d23",
+            Box::new(move |part, sink| d23::replay(&scan, part, sink)),
+        ),
+        (
+            "d24",
+            Box::new(move |part, sink| d24::replay(&valley, part, sink)),
+        ),
+    ];
+    for (day, replay) in &replays {
+        for part in [1, 2] {
+            let mut frames = Vec::<Frame>::new();
+            replay(part, &mut frames);
+            let mut last = FinalOnly::default();
+            replay(part, &mut last);
+            assert_eq!(last.0.as_slice(), &frames[frames.len() - 1..], "{}", day);
+        }
+    }
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// parse of d10, one instruction per line
+fuzz_target!(|input: &str| {
+    let _ = d10::parse(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// parse_monkey_block through parse, which splits the input into blocks
+fuzz_target!(|input: &str| {
+    let _ = d11::parse(input);
+});
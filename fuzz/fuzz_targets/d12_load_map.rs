@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// load_map, the parse of d12
+fuzz_target!(|input: &str| {
+    let _ = d12::parse(input);
+});
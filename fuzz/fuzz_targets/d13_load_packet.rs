@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// load_packet, the parse of d13
+fuzz_target!(|input: &str| {
+    let _ = d13::parse(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Game::new, the parse of d14
+fuzz_target!(|input: &str| {
+    let _ = d14::parse(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// load_sensor_list, the parse of d15
+fuzz_target!(|input: &str| {
+    let _ = d15::parse(input);
+});
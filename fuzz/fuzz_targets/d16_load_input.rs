@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// load_input, the parse of d16
+fuzz_target!(|input: &str| {
+    let _ = d16::parse(input);
+});
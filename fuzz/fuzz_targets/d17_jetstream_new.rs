@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Jetstream::new, the parse of d17
+fuzz_target!(|input: &str| {
+    let _ = d17::parse(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// read_input, the parse of d18
+fuzz_target!(|input: &str| {
+    let _ = d18::parse(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// read_input, the parse of d19
+fuzz_target!(|input: &str| {
+    let _ = d19::parse(input);
+});
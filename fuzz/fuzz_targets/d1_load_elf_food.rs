@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// load_elf_food, the parse of d1
+fuzz_target!(|input: &str| {
+    let _ = d1::parse(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// read_input, the parse of d20
+fuzz_target!(|input: &str| {
+    let _ = d20::parse(input);
+});
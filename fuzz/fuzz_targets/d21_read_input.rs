@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// read_input, the parse of d21
+fuzz_target!(|input: &str| {
+    let _ = d21::parse(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// handle_input, the parse of d23
+fuzz_target!(|input: &str| {
+    let _ = d23::parse(input);
+});
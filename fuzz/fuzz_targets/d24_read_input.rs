@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// read_input and the check of the openings, through parse
+fuzz_target!(|input: &str| {
+    let _ = d24::parse(input);
+});
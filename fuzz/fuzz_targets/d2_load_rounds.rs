@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// load_rounds, the parse of d2
+fuzz_target!(|input: &str| {
+    let _ = d2::parse(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// parse of d3, which checks every rucksack on its own line
+fuzz_target!(|input: &str| {
+    let _ = d3::parse(input);
+});
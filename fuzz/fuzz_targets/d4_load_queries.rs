@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// load_queries, the parse of d4
+fuzz_target!(|input: &str| {
+    let _ = d4::parse(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// get_crate_list through parse, which splits off the drawing of the stacks
+fuzz_target!(|input: &str| {
+    let _ = d5::parse(input);
+});
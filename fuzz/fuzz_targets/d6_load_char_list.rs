@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// load_char_list, the parse of d6
+fuzz_target!(|input: &str| {
+    let _ = d6::parse(input);
+});
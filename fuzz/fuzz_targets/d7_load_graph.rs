@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// load_graph, the parse of d7
+fuzz_target!(|input: &str| {
+    let _ = d7::parse(input);
+});
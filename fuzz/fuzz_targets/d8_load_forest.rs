@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// load_forest, the parse of d8
+fuzz_target!(|input: &str| {
+    let _ = d8::parse(input);
+});
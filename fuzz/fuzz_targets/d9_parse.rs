@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// parse of d9, one motion per line
+fuzz_target!(|input: &str| {
+    let _ = d9::parse(input);
+});
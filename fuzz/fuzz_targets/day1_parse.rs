@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// parse of 2023 day 1, one line of the calibration document each
+fuzz_target!(|input: &str| {
+    let _ = day1::parse(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// parse of 2023 day 2, one game per line
+fuzz_target!(|input: &str| {
+    let _ = day2::parse(input);
+});
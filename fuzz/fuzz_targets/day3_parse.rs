@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// parse of 2023 day 3, the engine schematic as a grid
+fuzz_target!(|input: &str| {
+    let _ = day3::parse(input);
+});
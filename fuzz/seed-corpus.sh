@@ -0,0 +1,26 @@
+#!/bin/sh
+# seeds the corpus of every fuzz target with the puzzle files checked in next
+# to its day, examples and inputs alike whatever they are called, run it once
+# before cargo fuzz run <target>
+set -e
+cd "$(dirname "$0")"
+
+for target in fuzz_targets/*.rs; do
+    target=$(basename "$target" .rs)
+    day=${target%%_*}
+    case $day in
+        day*) directory=../2023/$day ;;
+        *) directory=../2022/$day ;;
+    esac
+    mkdir -p "corpus/$target"
+    for example in "$directory"/*; do
+        name=$(basename "$example")
+        # the manifest, the recorded answers and debug output are no input
+        case $name in
+            Cargo.toml | *.json | output.txt) continue ;;
+        esac
+        if [ -f "$example" ]; then
+            cp "$example" "corpus/$target/$name"
+        fi
+    done
+done